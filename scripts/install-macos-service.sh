@@ -13,6 +13,12 @@ AUTH_REQUIRED="${AUTH_REQUIRED:-true}"
 REMINDCTL_BIN="${REMINDCTL_BIN:-remindctl}"
 REMINDCTL_READ_TIMEOUT_SECS="${REMINDCTL_READ_TIMEOUT_SECS:-60}"
 REMINDCTL_WRITE_TIMEOUT_SECS="${REMINDCTL_WRITE_TIMEOUT_SECS:-20}"
+SNAPSHOT_CACHE_TTL_SECS="${SNAPSHOT_CACHE_TTL_SECS:-5}"
+REMINDCTL_MAX_CONCURRENT_READS="${REMINDCTL_MAX_CONCURRENT_READS:-4}"
+REMINDCTL_MAX_RETRIES="${REMINDCTL_MAX_RETRIES:-2}"
+REMINDCTL_BREAKER_THRESHOLD="${REMINDCTL_BREAKER_THRESHOLD:-5}"
+REMINDCTL_BREAKER_COOLDOWN_SECS="${REMINDCTL_BREAKER_COOLDOWN_SECS:-30}"
+SERVER_TIMEZONE="${SERVER_TIMEZONE:-local}"
 API_KEY="${API_KEY:-}"
 LAUNCH_AGENTS_DIR="$HOME/Library/LaunchAgents"
 PLIST_PATH="$LAUNCH_AGENTS_DIR/${SERVICE_LABEL}.plist"
@@ -75,6 +81,18 @@ cat >"$PLIST_PATH" <<EOF
     <string>${REMINDCTL_READ_TIMEOUT_SECS}</string>
     <key>REMINDCTL_WRITE_TIMEOUT_SECS</key>
     <string>${REMINDCTL_WRITE_TIMEOUT_SECS}</string>
+    <key>SNAPSHOT_CACHE_TTL_SECS</key>
+    <string>${SNAPSHOT_CACHE_TTL_SECS}</string>
+    <key>REMINDCTL_MAX_CONCURRENT_READS</key>
+    <string>${REMINDCTL_MAX_CONCURRENT_READS}</string>
+    <key>REMINDCTL_MAX_RETRIES</key>
+    <string>${REMINDCTL_MAX_RETRIES}</string>
+    <key>REMINDCTL_BREAKER_THRESHOLD</key>
+    <string>${REMINDCTL_BREAKER_THRESHOLD}</string>
+    <key>REMINDCTL_BREAKER_COOLDOWN_SECS</key>
+    <string>${REMINDCTL_BREAKER_COOLDOWN_SECS}</string>
+    <key>SERVER_TIMEZONE</key>
+    <string>${SERVER_TIMEZONE}</string>
   </dict>
 
   <key>RunAtLoad</key>
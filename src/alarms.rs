@@ -0,0 +1,298 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::dates::{Due, ServerTimezone};
+use crate::error::AppError;
+use crate::phrases;
+
+/// Most alarms one reminder may carry.
+pub const MAX_ALARMS: usize = 10;
+
+/// Relative offsets beyond a year are almost certainly unit mistakes.
+const MAX_OFFSET_MINUTES: i64 = 366 * 24 * 60;
+
+/// An alert on a reminder: an offset from its due time, or a fixed instant.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Alarm {
+    /// Fires `minutesBefore` minutes before the due time; negative fires after it.
+    Relative {
+        #[serde(rename = "minutesBefore")]
+        minutes_before: i64,
+    },
+    /// Fires at `at`, UTC RFC3339.
+    Absolute { at: String },
+}
+
+/// What one `alarms` entry accepts: an offset phrase ("15 minutes before", "1 hora
+/// antes", "1h"), a date/time or due phrase for an absolute alarm, or an object.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum AlarmInput {
+    Text(String),
+    Relative {
+        #[serde(rename = "minutesBefore")]
+        minutes_before: i64,
+    },
+    Absolute {
+        at: String,
+    },
+}
+
+impl Alarm {
+    /// Parses one `alarms` entry; errors name the `alarms` field.
+    pub fn parse(value: &Value, tz: ServerTimezone, now: DateTime<Utc>) -> Result<Self, AppError> {
+        let alarm = match value {
+            Value::String(text) => Self::parse_text(text, tz, now)?,
+            Value::Number(number) => Self::relative(number.as_i64())?,
+            Value::Object(object) => {
+                if let Some(minutes) = object.get("minutesBefore") {
+                    Self::relative(minutes.as_i64())?
+                } else if let Some(at) = object.get("at").and_then(Value::as_str) {
+                    Self::absolute(at, tz, now)?
+                } else {
+                    return Err(invalid("expected {minutesBefore} or {at}"));
+                }
+            }
+            _ => {
+                return Err(invalid(
+                    "expected an offset like \"15 minutes before\", a time, or an object",
+                ));
+            }
+        };
+        Ok(alarm)
+    }
+
+    /// Parses the `alarms` input list, dropping duplicates.
+    pub fn parse_all(
+        values: &[Value],
+        tz: ServerTimezone,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Self>, AppError> {
+        if values.len() > MAX_ALARMS {
+            return Err(invalid(format!("at most {MAX_ALARMS} alarms per reminder")));
+        }
+        let mut alarms = Vec::with_capacity(values.len());
+        for value in values {
+            let alarm = Self::parse(value, tz, now)?;
+            if !alarms.contains(&alarm) {
+                alarms.push(alarm);
+            }
+        }
+        Ok(alarms)
+    }
+
+    fn parse_text(text: &str, tz: ServerTimezone, now: DateTime<Utc>) -> Result<Self, AppError> {
+        match phrases::minutes_before(text) {
+            Some(minutes) => Self::relative(Some(minutes)),
+            None => Self::absolute(text, tz, now),
+        }
+    }
+
+    fn relative(minutes: Option<i64>) -> Result<Self, AppError> {
+        match minutes {
+            Some(minutes) if minutes.abs() <= MAX_OFFSET_MINUTES => Ok(Self::Relative {
+                minutes_before: minutes,
+            }),
+            _ => Err(invalid(format!(
+                "minutesBefore must be a whole number of minutes within {MAX_OFFSET_MINUTES} of the due time"
+            ))),
+        }
+    }
+
+    fn absolute(text: &str, tz: ServerTimezone, now: DateTime<Utc>) -> Result<Self, AppError> {
+        match Due::resolve(text, tz, now) {
+            Ok(Due::At(at)) => Ok(Self::Absolute {
+                at: Due::At(at).remindctl_arg(),
+            }),
+            Ok(Due::AllDay(_)) => Err(invalid(format!(
+                "'{text}' has no time of day; absolute alarms need one, like \"{text} at 9am\""
+            ))),
+            Err(_) => Err(invalid(format!(
+                "'{text}' is not an alarm; use an offset like \"15 minutes before\" or \"1 hora antes\", \
+                 a time like 2026-03-01T09:00, or {{minutesBefore}}/{{at}}"
+            ))),
+        }
+    }
+
+    pub fn is_relative(&self) -> bool {
+        matches!(self, Self::Relative { .. })
+    }
+
+    /// The `--alarm` argument handed to remindctl: an EventKit-style offset (`-15m` is
+    /// 15 minutes before due) or a UTC instant.
+    pub fn remindctl_arg(&self) -> String {
+        match self {
+            Self::Relative { minutes_before } if *minutes_before > 0 => {
+                format!("-{minutes_before}m")
+            }
+            Self::Relative { minutes_before } => format!("+{}m", minutes_before.abs()),
+            Self::Absolute { at } => at.clone(),
+        }
+    }
+
+    /// The alarm with a floating absolute time from remindctl read in the server
+    /// timezone; anything else is returned unchanged.
+    pub fn localized(self, tz: ServerTimezone) -> Self {
+        match &self {
+            Self::Absolute { at } => match Due::parse(at, tz) {
+                Ok(Due::At(at)) => Self::Absolute {
+                    at: Due::At(at).remindctl_arg(),
+                },
+                _ => self,
+            },
+            Self::Relative { .. } => self,
+        }
+    }
+
+    /// Reads one alarm from remindctl output: this model, an `--alarm` style string, or
+    /// EventKit's `relativeOffset` (seconds, negative before due) / `absoluteDate`.
+    /// Floating times are kept as written for [`Self::localized`].
+    fn from_remindctl(value: &Value) -> Option<Self> {
+        if let Ok(alarm) = serde_json::from_value::<Self>(value.clone()) {
+            return Some(alarm);
+        }
+        if let Some(seconds) = value.get("relativeOffset").and_then(Value::as_f64) {
+            return Some(Self::Relative {
+                minutes_before: (-seconds / 60.0).round() as i64,
+            });
+        }
+        let text = value
+            .get("absoluteDate")
+            .and_then(Value::as_str)
+            .or_else(|| value.as_str())?;
+        if let Some(offset) = text.strip_suffix('m').and_then(|m| m.parse::<i64>().ok()) {
+            return Some(Self::Relative {
+                minutes_before: -offset,
+            });
+        }
+        // UTC only tells a time from a date here; the timezone is applied later.
+        match Due::parse(text, ServerTimezone::Named(chrono_tz::UTC)).ok()? {
+            Due::At(_) => Some(Self::Absolute {
+                at: text.trim().to_owned(),
+            }),
+            Due::AllDay(_) => None,
+        }
+    }
+}
+
+fn invalid(message: impl Into<String>) -> AppError {
+    AppError::invalid_field("alarms", message)
+}
+
+/// Reads `alarms` from remindctl output, dropping (and logging) entries it cannot read.
+pub fn deserialize_lenient<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec<Alarm>>, D::Error> {
+    let value = Option::<Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| match value {
+        Value::Array(items) => Some(
+            items
+                .iter()
+                .filter_map(|item| {
+                    let alarm = Alarm::from_remindctl(item);
+                    if alarm.is_none() {
+                        tracing::warn!(alarm = %item, "ignoring unreadable alarm");
+                    }
+                    alarm
+                })
+                .collect(),
+        ),
+        _ => None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn madrid() -> ServerTimezone {
+        ServerTimezone::Named(chrono_tz::Europe::Madrid)
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 11, 9, 0, 0)
+            .single()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn parses_offsets_times_and_objects() -> Result<(), AppError> {
+        let inputs = serde_json::json!([
+            "1 hour before",
+            "15m",
+            { "minutesBefore": 60 },
+            "2026-03-12T08:00",
+            { "at": "mañana a las 10" }
+        ]);
+        let alarms = Alarm::parse_all(
+            inputs.as_array().map_or(&[], Vec::as_slice),
+            madrid(),
+            now(),
+        )?;
+        let args = alarms.iter().map(Alarm::remindctl_arg).collect::<Vec<_>>();
+        assert_eq!(
+            args,
+            vec![
+                "-60m",
+                "-15m",
+                "2026-03-12T07:00:00Z",
+                "2026-03-12T09:00:00Z"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_all_day_and_unreadable_alarms() {
+        for input in [
+            serde_json::json!("tomorrow"),
+            serde_json::json!("whenever"),
+            serde_json::json!({ "minutesBefore": "soon" }),
+            serde_json::json!(true),
+        ] {
+            let err = Alarm::parse(&input, madrid(), now()).err();
+            assert_eq!(
+                err.as_ref().and_then(AppError::field),
+                Some("alarms"),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn reads_eventkit_and_cli_shapes_from_remindctl() {
+        let read = |value: Value| Alarm::from_remindctl(&value);
+        assert_eq!(
+            read(serde_json::json!({ "relativeOffset": -900.0 })),
+            Some(Alarm::Relative { minutes_before: 15 })
+        );
+        assert_eq!(
+            read(serde_json::json!("-30m")),
+            Some(Alarm::Relative { minutes_before: 30 })
+        );
+        assert_eq!(
+            read(serde_json::json!({ "absoluteDate": "2026-03-12T07:00:00Z" })),
+            Some(Alarm::Absolute {
+                at: "2026-03-12T07:00:00Z".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn floating_remindctl_times_are_read_in_the_server_timezone() {
+        let alarm =
+            Alarm::from_remindctl(&serde_json::json!({ "absoluteDate": "2026-03-12T08:00" }))
+                .map(|alarm| alarm.localized(madrid()));
+        assert_eq!(
+            alarm,
+            Some(Alarm::Absolute {
+                at: "2026-03-12T07:00:00Z".to_owned()
+            })
+        );
+    }
+}
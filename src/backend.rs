@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+
+use crate::alarms::Alarm;
+use crate::error::AppError;
+use crate::models::{RemindctlStatus, Reminder, ReminderList};
+use crate::priority::Priority;
+use crate::recurrence::Recurrence;
+
+/// Fields for a reminder about to be created. List placement is already resolved to a name.
+#[derive(Debug, Clone, Default)]
+pub struct NewReminder {
+    pub title: String,
+    pub list_name: Option<String>,
+    pub due: Option<String>,
+    pub notes: Option<String>,
+    pub priority: Option<Priority>,
+    pub recurrence: Option<Recurrence>,
+    pub alarms: Vec<Alarm>,
+}
+
+/// Partial update for an existing reminder. `None` leaves the field untouched.
+#[derive(Debug, Clone, Default)]
+pub struct ReminderUpdate {
+    pub title: Option<String>,
+    pub list_name: Option<String>,
+    pub due: Option<String>,
+    pub clear_due: bool,
+    pub notes: Option<String>,
+    pub priority: Option<Priority>,
+    pub recurrence: Option<Recurrence>,
+    pub clear_recurrence: bool,
+    /// Alarms to add; set `clear_alarms` too to replace the existing ones.
+    pub alarms: Vec<Alarm>,
+    pub clear_alarms: bool,
+    pub complete: Option<bool>,
+}
+
+/// Storage operations the MCP layer needs. Reminder IDs passed in are already resolved to full IDs.
+#[async_trait]
+pub trait ReminderBackend: Send + Sync {
+    async fn status(&self) -> Result<RemindctlStatus, AppError>;
+
+    async fn lists(&self) -> Result<Vec<ReminderList>, AppError>;
+
+    /// Reminders matching a remindctl filter keyword or date, optionally scoped to one list.
+    async fn show(&self, filter: &str, list_name: Option<&str>) -> Result<Vec<Reminder>, AppError>;
+
+    async fn add(&self, reminder: NewReminder) -> Result<Reminder, AppError>;
+
+    async fn edit(&self, id: &str, update: ReminderUpdate) -> Result<Reminder, AppError>;
+
+    async fn complete(&self, ids: &[String], dry_run: bool) -> Result<Vec<Reminder>, AppError>;
+
+    async fn delete(&self, ids: &[String], dry_run: bool) -> Result<Vec<Reminder>, AppError>;
+
+    async fn list_create(&self, name: &str) -> Result<(), AppError>;
+
+    async fn list_rename(&self, name: &str, new_name: &str) -> Result<(), AppError>;
+
+    async fn list_delete(&self, name: &str) -> Result<(), AppError>;
+}
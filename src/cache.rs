@@ -0,0 +1,289 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use crate::backend::{NewReminder, ReminderBackend, ReminderUpdate};
+use crate::error::AppError;
+use crate::models::{CacheStats, RemindctlStatus, Reminder, ReminderList};
+
+#[derive(Debug)]
+struct Snapshot<T> {
+    value: T,
+    fetched_at: Instant,
+}
+
+/// TTL snapshots of `list` and `show all`, shared by every session. A zero TTL disables caching.
+#[derive(Debug)]
+pub struct SnapshotCache {
+    ttl: Duration,
+    lists: Mutex<Option<Snapshot<Vec<ReminderList>>>>,
+    reminders: Mutex<Option<Snapshot<Vec<Reminder>>>>,
+    generation: AtomicU64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl SnapshotCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            lists: Mutex::new(None),
+            reminders: Mutex::new(None),
+            generation: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        CacheStats {
+            ttl_secs: self.ttl.as_secs(),
+            hits,
+            misses,
+            hit_rate: if total == 0 {
+                0.0
+            } else {
+                hits as f64 / total as f64
+            },
+            lists_age_secs: self.fresh_age(&self.lists),
+            reminders_age_secs: self.fresh_age(&self.reminders),
+        }
+    }
+
+    fn enabled(&self) -> bool {
+        !self.ttl.is_zero()
+    }
+
+    fn fresh_age<T>(&self, slot: &Mutex<Option<Snapshot<T>>>) -> Option<u64> {
+        let slot = slot.lock().ok()?;
+        let age = slot.as_ref()?.fetched_at.elapsed();
+        (age < self.ttl).then_some(age.as_secs())
+    }
+
+    fn get<T: Clone>(&self, slot: &Mutex<Option<Snapshot<T>>>) -> Option<T> {
+        if !self.enabled() {
+            return None;
+        }
+        let value = slot.lock().ok().and_then(|slot| {
+            slot.as_ref()
+                .filter(|snapshot| snapshot.fetched_at.elapsed() < self.ttl)
+                .map(|snapshot| snapshot.value.clone())
+        });
+        let counter = if value.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    /// Stores a fetch result unless a write happened since the fetch started.
+    fn store<T>(&self, slot: &Mutex<Option<Snapshot<T>>>, value: T, generation: u64) {
+        if !self.enabled() || self.generation.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        if let Ok(mut slot) = slot.lock() {
+            *slot = Some(Snapshot {
+                value,
+                fetched_at: Instant::now(),
+            });
+        }
+    }
+
+    fn current_generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    pub fn invalidate(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut lists) = self.lists.lock() {
+            *lists = None;
+        }
+        if let Ok(mut reminders) = self.reminders.lock() {
+            *reminders = None;
+        }
+    }
+
+    /// Applies a successful reminder write to the `show all` snapshot in place. List
+    /// counts change with every reminder write, so the lists snapshot is dropped.
+    fn patch_reminders(&self, patch: impl FnOnce(&mut Vec<Reminder>)) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut lists) = self.lists.lock() {
+            *lists = None;
+        }
+        if let Ok(mut reminders) = self.reminders.lock()
+            && let Some(snapshot) = reminders.as_mut()
+        {
+            patch(&mut snapshot.value);
+        }
+    }
+}
+
+/// Backend decorator that serves `lists` and `show all` from a [`SnapshotCache`]. A failed
+/// write may have partially applied, so it drops both snapshots.
+pub struct CachingBackend {
+    inner: Arc<dyn ReminderBackend>,
+    cache: Arc<SnapshotCache>,
+}
+
+impl CachingBackend {
+    pub fn new(inner: Arc<dyn ReminderBackend>, cache: Arc<SnapshotCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl ReminderBackend for CachingBackend {
+    async fn status(&self) -> Result<RemindctlStatus, AppError> {
+        self.inner.status().await
+    }
+
+    async fn lists(&self) -> Result<Vec<ReminderList>, AppError> {
+        if let Some(lists) = self.cache.get(&self.cache.lists) {
+            return Ok(lists);
+        }
+        let generation = self.cache.current_generation();
+        let lists = self.inner.lists().await?;
+        self.cache
+            .store(&self.cache.lists, lists.clone(), generation);
+        Ok(lists)
+    }
+
+    async fn show(&self, filter: &str, list_name: Option<&str>) -> Result<Vec<Reminder>, AppError> {
+        if filter != "all" || list_name.is_some() {
+            return self.inner.show(filter, list_name).await;
+        }
+        if let Some(reminders) = self.cache.get(&self.cache.reminders) {
+            return Ok(reminders);
+        }
+        let generation = self.cache.current_generation();
+        let reminders = self.inner.show(filter, None).await?;
+        self.cache
+            .store(&self.cache.reminders, reminders.clone(), generation);
+        Ok(reminders)
+    }
+
+    async fn add(&self, reminder: NewReminder) -> Result<Reminder, AppError> {
+        let created = self
+            .inner
+            .add(reminder)
+            .await
+            .inspect_err(|_| self.cache.invalidate())?;
+        self.cache.patch_reminders(|all| all.push(created.clone()));
+        Ok(created)
+    }
+
+    async fn edit(&self, id: &str, update: ReminderUpdate) -> Result<Reminder, AppError> {
+        let edited = self
+            .inner
+            .edit(id, update)
+            .await
+            .inspect_err(|_| self.cache.invalidate())?;
+        self.cache
+            .patch_reminders(|all| replace_reminders(all, std::slice::from_ref(&edited)));
+        Ok(edited)
+    }
+
+    async fn complete(&self, ids: &[String], dry_run: bool) -> Result<Vec<Reminder>, AppError> {
+        let completed = self
+            .inner
+            .complete(ids, dry_run)
+            .await
+            .inspect_err(|_| self.cache.invalidate())?;
+        if !dry_run {
+            self.cache
+                .patch_reminders(|all| replace_reminders(all, &completed));
+        }
+        Ok(completed)
+    }
+
+    async fn delete(&self, ids: &[String], dry_run: bool) -> Result<Vec<Reminder>, AppError> {
+        let deleted = self
+            .inner
+            .delete(ids, dry_run)
+            .await
+            .inspect_err(|_| self.cache.invalidate())?;
+        if !dry_run {
+            self.cache
+                .patch_reminders(|all| all.retain(|reminder| !ids.contains(&reminder.id)));
+        }
+        Ok(deleted)
+    }
+
+    async fn list_create(&self, name: &str) -> Result<(), AppError> {
+        self.inner
+            .list_create(name)
+            .await
+            .inspect_err(|_| self.cache.invalidate())?;
+        self.cache.invalidate();
+        Ok(())
+    }
+
+    async fn list_rename(&self, name: &str, new_name: &str) -> Result<(), AppError> {
+        self.inner
+            .list_rename(name, new_name)
+            .await
+            .inspect_err(|_| self.cache.invalidate())?;
+        self.cache.invalidate();
+        Ok(())
+    }
+
+    async fn list_delete(&self, name: &str) -> Result<(), AppError> {
+        self.inner
+            .list_delete(name)
+            .await
+            .inspect_err(|_| self.cache.invalidate())?;
+        self.cache.invalidate();
+        Ok(())
+    }
+}
+
+fn replace_reminders(all: &mut [Reminder], updated: &[Reminder]) {
+    for reminder in all.iter_mut() {
+        if let Some(fresh) = updated.iter().find(|fresh| fresh.id == reminder.id) {
+            *reminder = fresh.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemoryBackend;
+
+    #[tokio::test]
+    async fn writes_patch_snapshot_instead_of_refetching() -> Result<(), AppError> {
+        let cache = Arc::new(SnapshotCache::new(Duration::from_secs(60)));
+        let backend = CachingBackend::new(Arc::new(MemoryBackend::new()), Arc::clone(&cache));
+
+        assert!(backend.show("all", None).await?.is_empty());
+        let created = backend
+            .add(NewReminder {
+                title: "Buy milk".to_owned(),
+                ..Default::default()
+            })
+            .await?;
+        let all = backend.show("all", None).await?;
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].id, created.id);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn stale_fetch_is_not_stored_after_write() {
+        let cache = SnapshotCache::new(Duration::from_secs(60));
+        let generation = cache.current_generation();
+        cache.invalidate();
+        cache.store(&cache.lists, Vec::new(), generation);
+        assert!(cache.get(&cache.lists).is_none());
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::error::AppError;
+use crate::models::CapabilityReport;
+
+/// Subcommands the server drives, probed with `<command> --help` at startup.
+pub const PROBED_COMMANDS: [&str; 6] = ["show", "add", "edit", "complete", "delete", "list"];
+
+/// Flags tools depend on beyond the basics, reported in health and config.
+const REQUIRED_FLAGS: [(&str, &str); 15] = [
+    ("add", "--priority"),
+    ("add", "--recurrence"),
+    ("add", "--alarm"),
+    ("edit", "--priority"),
+    ("edit", "--clear-due"),
+    ("edit", "--recurrence"),
+    ("edit", "--clear-recurrence"),
+    ("edit", "--alarm"),
+    ("edit", "--clear-alarms"),
+    ("complete", "--dry-run"),
+    ("delete", "--dry-run"),
+    ("delete", "--force"),
+    ("list", "--create"),
+    ("list", "--rename"),
+    ("list", "--delete"),
+];
+
+/// Flags only newer `remindctl` releases have. These are never assumed: unless the
+/// command's `--help` lists them, tools using them fail with `unsupported` instead of
+/// handing an older binary flags it does not know.
+const CONFIRMED_FLAGS: [(&str, &str); 6] = [
+    ("add", "--recurrence"),
+    ("add", "--alarm"),
+    ("edit", "--recurrence"),
+    ("edit", "--clear-recurrence"),
+    ("edit", "--alarm"),
+    ("edit", "--clear-alarms"),
+];
+
+/// What the installed `remindctl` supports, parsed from its `--version` and `--help`
+/// output. Anything else that could not be probed is assumed supported, so a binary
+/// with unusual help text degrades to the old behaviour instead of blocking every tool.
+#[derive(Debug, Clone, Default)]
+pub struct Capabilities {
+    version: Option<String>,
+    subcommands: Option<BTreeSet<String>>,
+    flags: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Capabilities {
+    pub fn unknown() -> Self {
+        Self::default()
+    }
+
+    /// Builds the set from probe output. `top_help` is `remindctl --help`;
+    /// `command_helps` pairs each probed subcommand with its `--help` output.
+    pub fn from_probe(
+        version: Option<String>,
+        top_help: Option<&str>,
+        command_helps: &[(&str, String)],
+    ) -> Self {
+        let subcommands = top_help.and_then(|help| {
+            let words = help
+                .split(|ch: char| !ch.is_ascii_alphanumeric() && ch != '-')
+                .collect::<BTreeSet<_>>();
+            let found = PROBED_COMMANDS
+                .iter()
+                .filter(|command| words.contains(*command))
+                .map(|command| (*command).to_owned())
+                .collect::<BTreeSet<_>>();
+            (!found.is_empty()).then_some(found)
+        });
+
+        let flags = command_helps
+            .iter()
+            .filter_map(|(command, help)| {
+                let flags = help
+                    .split(|ch: char| ch.is_whitespace() || matches!(ch, ',' | '=' | '[' | ']'))
+                    .filter(|token| token.starts_with("--") && token.len() > 2)
+                    .map(|token| token.trim_end_matches(['.', ':', ')']).to_owned())
+                    .collect::<BTreeSet<_>>();
+                (!flags.is_empty()).then(|| ((*command).to_owned(), flags))
+            })
+            .collect();
+
+        Self {
+            version: version.filter(|version| !version.is_empty()),
+            subcommands,
+            flags,
+        }
+    }
+
+    pub fn probed(&self) -> bool {
+        self.version.is_some() || self.subcommands.is_some() || !self.flags.is_empty()
+    }
+
+    /// False when probing showed the command or flag is missing, or when a
+    /// [`CONFIRMED_FLAGS`] flag could not be probed at all.
+    pub fn supports(&self, command: &str, flag: Option<&str>) -> bool {
+        if let Some(subcommands) = &self.subcommands
+            && !subcommands.contains(command)
+        {
+            return false;
+        }
+        match (flag, self.flags.get(command)) {
+            (Some(flag), Some(flags)) => flags.contains(flag),
+            (Some(flag), None) => !CONFIRMED_FLAGS.contains(&(command, flag)),
+            (None, _) => true,
+        }
+    }
+
+    pub fn require(&self, command: &str, flag: Option<&str>) -> Result<(), AppError> {
+        if self.supports(command, flag) {
+            return Ok(());
+        }
+        let feature = match flag {
+            Some(flag) => format!("`{command} {flag}`"),
+            None => format!("`{command}`"),
+        };
+        let version = self.version.as_deref().unwrap_or("unknown version");
+        if flag.is_some() && !self.flags.contains_key(command) {
+            return Err(AppError::Unsupported(format!(
+                "could not confirm that installed remindctl ({version}) supports {feature}: \
+                 `remindctl {command} --help` did not list its flags; upgrade remindctl"
+            )));
+        }
+        Err(AppError::Unsupported(format!(
+            "installed remindctl ({version}) does not support {feature}; upgrade remindctl"
+        )))
+    }
+
+    pub fn report(&self) -> CapabilityReport {
+        let mut supported = Vec::new();
+        let mut unsupported = Vec::new();
+        for (command, flag) in REQUIRED_FLAGS {
+            let feature = format!("{command} {flag}");
+            if self.supports(command, Some(flag)) {
+                supported.push(feature);
+            } else {
+                unsupported.push(feature);
+            }
+        }
+        for command in PROBED_COMMANDS {
+            if !self.supports(command, None) {
+                unsupported.push(command.to_owned());
+            }
+        }
+        CapabilityReport {
+            version: self.version.clone(),
+            probed: self.probed(),
+            supported,
+            unsupported,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_flags_are_unsupported_and_unprobed_commands_are_assumed() {
+        let capabilities = Capabilities::from_probe(
+            Some("remindctl 0.3.0".to_owned()),
+            Some("SUBCOMMANDS:\n  show, add, edit, complete, delete, list"),
+            &[(
+                "edit",
+                "OPTIONS:\n  --title <title>\n  --due <due>, --priority <p>\n".to_owned(),
+            )],
+        );
+
+        assert!(capabilities.supports("edit", Some("--priority")));
+        assert!(!capabilities.supports("edit", Some("--clear-due")));
+        assert!(capabilities.supports("delete", Some("--force")));
+        assert!(matches!(
+            capabilities.require("edit", Some("--clear-due")),
+            Err(AppError::Unsupported(message)) if message.contains("0.3.0")
+        ));
+        assert_eq!(
+            capabilities.report().unsupported,
+            vec![
+                "add --recurrence",
+                "add --alarm",
+                "edit --clear-due",
+                "edit --recurrence",
+                "edit --clear-recurrence",
+                "edit --alarm",
+                "edit --clear-alarms"
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_probe_output_stays_permissive() {
+        let capabilities = Capabilities::from_probe(None, Some(""), &[("edit", String::new())]);
+        assert!(!capabilities.probed());
+        assert!(capabilities.supports("list", Some("--rename")));
+    }
+
+    #[test]
+    fn unprobed_recurrence_and_alarm_flags_are_unsupported() {
+        let capabilities = Capabilities::unknown();
+        assert!(capabilities.supports("edit", Some("--clear-due")));
+        for (command, flag) in CONFIRMED_FLAGS {
+            assert!(
+                matches!(
+                    capabilities.require(command, Some(flag)),
+                    Err(AppError::Unsupported(message)) if message.contains("could not confirm")
+                ),
+                "{command} {flag}"
+            );
+        }
+    }
+}
@@ -1,22 +1,73 @@
 use std::env;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::dates::ServerTimezone;
 use crate::error::AppError;
 
 const DEFAULT_BIND_ADDR: &str = "127.0.0.1:8787";
 const DEFAULT_AUTH_REQUIRED: bool = true;
 const DEFAULT_READ_TIMEOUT_SECS: u64 = 10;
 const DEFAULT_WRITE_TIMEOUT_SECS: u64 = 20;
+const DEFAULT_CACHE_TTL_SECS: u64 = 5;
+const DEFAULT_MAX_CONCURRENT_READS: u64 = 4;
+const DEFAULT_MAX_RETRIES: u64 = 2;
+const DEFAULT_RETRY_BASE_MS: u64 = 200;
+const DEFAULT_BREAKER_THRESHOLD: u64 = 5;
+const DEFAULT_BREAKER_COOLDOWN_SECS: u64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendKind {
+    Remindctl,
+    Memory,
+}
+
+impl BackendKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Remindctl => "remindctl",
+            Self::Memory => "memory",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureMode {
+    Off,
+    Record,
+    Replay,
+}
+
+impl FixtureMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Record => "record",
+            Self::Replay => "replay",
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub bind_addr: SocketAddr,
     pub auth_required: bool,
     pub api_key: Option<String>,
+    pub backend: BackendKind,
+    pub memory_fixture: Option<PathBuf>,
     pub remindctl_bin: String,
+    pub fixture_mode: FixtureMode,
+    pub fixture_dir: Option<PathBuf>,
     pub read_timeout: Duration,
     pub write_timeout: Duration,
+    pub cache_ttl: Duration,
+    pub max_concurrent_reads: usize,
+    pub max_retries: u32,
+    pub retry_base_delay: Duration,
+    pub breaker_threshold: u32,
+    pub breaker_cooldown: Duration,
+    pub timezone: ServerTimezone,
 }
 
 impl Config {
@@ -35,8 +86,26 @@ impl Config {
             ));
         }
 
+        let backend = parse_backend_env("BACKEND", BackendKind::Remindctl)?;
+        let memory_fixture = env::var("MEMORY_FIXTURE")
+            .ok()
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from);
+
         let remindctl_bin = env::var("REMINDCTL_BIN").unwrap_or_else(|_| "remindctl".to_owned());
 
+        let fixture_mode = parse_fixture_mode_env("REMINDCTL_FIXTURE_MODE", FixtureMode::Off)?;
+        let fixture_dir = env::var("REMINDCTL_FIXTURE_DIR")
+            .ok()
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from);
+
+        if fixture_mode != FixtureMode::Off && fixture_dir.is_none() {
+            return Err(AppError::invalid_config(
+                "REMINDCTL_FIXTURE_DIR must be set when REMINDCTL_FIXTURE_MODE is record or replay",
+            ));
+        }
+
         let read_timeout = Duration::from_secs(parse_u64_env(
             "REMINDCTL_READ_TIMEOUT_SECS",
             DEFAULT_READ_TIMEOUT_SECS,
@@ -46,13 +115,60 @@ impl Config {
             DEFAULT_WRITE_TIMEOUT_SECS,
         )?);
 
+        let cache_ttl = Duration::from_secs(parse_u64_env(
+            "SNAPSHOT_CACHE_TTL_SECS",
+            DEFAULT_CACHE_TTL_SECS,
+        )?);
+
+        let max_concurrent_reads = parse_u64_env(
+            "REMINDCTL_MAX_CONCURRENT_READS",
+            DEFAULT_MAX_CONCURRENT_READS,
+        )?;
+        if max_concurrent_reads == 0 {
+            return Err(AppError::invalid_config(
+                "REMINDCTL_MAX_CONCURRENT_READS must be at least 1",
+            ));
+        }
+
+        let max_retries = parse_u64_env("REMINDCTL_MAX_RETRIES", DEFAULT_MAX_RETRIES)?;
+        let retry_base_delay = Duration::from_millis(parse_u64_env(
+            "REMINDCTL_RETRY_BASE_MS",
+            DEFAULT_RETRY_BASE_MS,
+        )?);
+        let breaker_threshold =
+            parse_u64_env("REMINDCTL_BREAKER_THRESHOLD", DEFAULT_BREAKER_THRESHOLD)?;
+        let breaker_cooldown = Duration::from_secs(parse_u64_env(
+            "REMINDCTL_BREAKER_COOLDOWN_SECS",
+            DEFAULT_BREAKER_COOLDOWN_SECS,
+        )?);
+
+        let timezone = match env::var("SERVER_TIMEZONE") {
+            Ok(value) if !value.is_empty() => ServerTimezone::parse(&value).ok_or_else(|| {
+                AppError::invalid_config(
+                    "invalid SERVER_TIMEZONE, expected local or an IANA name like Europe/Madrid",
+                )
+            })?,
+            _ => ServerTimezone::Local,
+        };
+
         Ok(Self {
             bind_addr,
             auth_required,
             api_key,
+            backend,
+            memory_fixture,
             remindctl_bin,
+            fixture_mode,
+            fixture_dir,
             read_timeout,
             write_timeout,
+            cache_ttl,
+            max_concurrent_reads: max_concurrent_reads as usize,
+            max_retries: max_retries.min(u64::from(u32::MAX)) as u32,
+            retry_base_delay,
+            breaker_threshold: breaker_threshold.min(u64::from(u32::MAX)) as u32,
+            breaker_cooldown,
+            timezone,
         })
     }
 
@@ -60,15 +176,34 @@ impl Config {
         tracing::info!(
             auth_required = self.auth_required,
             bind_addr = %self.bind_addr,
+            backend = self.backend.as_str(),
             remindctl_bin = %self.remindctl_bin,
             read_timeout_secs = self.read_timeout.as_secs(),
             write_timeout_secs = self.write_timeout.as_secs(),
+            cache_ttl_secs = self.cache_ttl.as_secs(),
+            max_concurrent_reads = self.max_concurrent_reads,
+            max_retries = self.max_retries,
+            breaker_threshold = self.breaker_threshold,
+            breaker_cooldown_secs = self.breaker_cooldown.as_secs(),
+            timezone = %self.timezone,
             "starting remindctl mcp server",
         );
 
         if !self.auth_required {
             tracing::warn!("AUTH_REQUIRED=false, API key auth is disabled");
         }
+
+        if self.fixture_mode != FixtureMode::Off {
+            tracing::warn!(
+                fixture_mode = self.fixture_mode.as_str(),
+                fixture_dir = ?self.fixture_dir,
+                "remindctl fixture mode enabled",
+            );
+        }
+
+        if self.backend == BackendKind::Memory {
+            tracing::warn!("BACKEND=memory, reminders are not persisted");
+        }
     }
 }
 
@@ -85,6 +220,33 @@ fn parse_bool_env(key: &str, default: bool) -> Result<bool, AppError> {
     }
 }
 
+fn parse_backend_env(key: &str, default: BackendKind) -> Result<BackendKind, AppError> {
+    match env::var(key) {
+        Ok(value) => match value.as_str() {
+            "remindctl" => Ok(BackendKind::Remindctl),
+            "memory" => Ok(BackendKind::Memory),
+            _ => Err(AppError::invalid_config(format!(
+                "invalid {key} value, expected remindctl or memory"
+            ))),
+        },
+        Err(_) => Ok(default),
+    }
+}
+
+fn parse_fixture_mode_env(key: &str, default: FixtureMode) -> Result<FixtureMode, AppError> {
+    match env::var(key) {
+        Ok(value) => match value.as_str() {
+            "off" => Ok(FixtureMode::Off),
+            "record" => Ok(FixtureMode::Record),
+            "replay" => Ok(FixtureMode::Replay),
+            _ => Err(AppError::invalid_config(format!(
+                "invalid {key} value, expected off, record or replay"
+            ))),
+        },
+        Err(_) => Ok(default),
+    }
+}
+
 fn parse_u64_env(key: &str, default: u64) -> Result<u64, AppError> {
     match env::var(key) {
         Ok(value) => value
@@ -0,0 +1,217 @@
+use std::fmt;
+
+use chrono::{
+    DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+};
+use chrono_tz::Tz;
+
+use crate::error::AppError;
+use crate::models::DueDate;
+use crate::phrases;
+
+const UTC_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const NAIVE_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+];
+
+/// Timezone that offset-less due inputs are read in and `local` fields are rendered in.
+/// `Local` follows the host, which is what remindctl itself uses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ServerTimezone {
+    #[default]
+    Local,
+    Named(Tz),
+}
+
+impl ServerTimezone {
+    /// `local` or an IANA name such as `Europe/Madrid`.
+    pub fn parse(value: &str) -> Option<Self> {
+        if value.eq_ignore_ascii_case("local") {
+            return Some(Self::Local);
+        }
+        value.parse::<Tz>().ok().map(Self::Named)
+    }
+
+    fn offset_at(self, instant: DateTime<Utc>) -> FixedOffset {
+        match self {
+            Self::Local => instant.with_timezone(&Local).offset().fix(),
+            Self::Named(tz) => instant.with_timezone(&tz).offset().fix(),
+        }
+    }
+
+    pub fn to_local(self, instant: DateTime<Utc>) -> DateTime<FixedOffset> {
+        instant.with_timezone(&self.offset_at(instant))
+    }
+
+    /// The instant a wall-clock time names here. Repeated times (DST fall-back) take the
+    /// earlier one; skipped times (spring-forward) have none.
+    pub fn from_local(self, naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Local => Local
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|local| local.with_timezone(&Utc)),
+            Self::Named(tz) => tz
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|local| local.with_timezone(&Utc)),
+        }
+    }
+}
+
+impl fmt::Display for ServerTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local => f.write_str("local"),
+            Self::Named(tz) => f.write_str(tz.name()),
+        }
+    }
+}
+
+/// A validated due date: a whole day, or an exact instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Due {
+    AllDay(NaiveDate),
+    At(DateTime<Utc>),
+}
+
+impl Due {
+    /// Parses tool input: `YYYY-MM-DD` (all-day), RFC3339 with an offset, or a
+    /// `YYYY-MM-DDTHH:MM[:SS]` wall-clock time in `tz`.
+    pub fn parse(value: &str, tz: ServerTimezone) -> Result<Self, AppError> {
+        let value = value.trim();
+        if let Ok(date) = NaiveDate::parse_from_str(value, DATE_FORMAT) {
+            return Ok(Self::AllDay(date));
+        }
+        if let Ok(instant) = DateTime::parse_from_rfc3339(value) {
+            return Ok(Self::At(instant.with_timezone(&Utc)));
+        }
+        let Some(naive) = NAIVE_FORMATS
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        else {
+            return Err(AppError::invalid_field(
+                "due",
+                format!(
+                    "'{value}' is not a date; use YYYY-MM-DD, YYYY-MM-DDTHH:MM[:SS] \
+                     (server timezone {tz}), RFC3339 like 2026-03-01T14:30:00Z, or a \
+                     phrase like \"tomorrow at 5pm\" or \"mañana a las 9\""
+                ),
+            ));
+        };
+        tz.from_local(naive).map(Self::At).ok_or_else(|| {
+            AppError::invalid_field(
+                "due",
+                format!("'{value}' does not exist in {tz} (skipped by a DST change)"),
+            )
+        })
+    }
+
+    /// Parses a `due` tool input: anything [`Due::parse`] accepts, or an English or
+    /// Spanish phrase resolved against `now`.
+    pub fn resolve(value: &str, tz: ServerTimezone, now: DateTime<Utc>) -> Result<Self, AppError> {
+        Self::parse(value, tz).or_else(|err| phrases::resolve(value, tz, now).ok_or(err))
+    }
+
+    /// The `--due` argument handed to remindctl.
+    pub fn remindctl_arg(&self) -> String {
+        match self {
+            Self::AllDay(date) => date.format(DATE_FORMAT).to_string(),
+            Self::At(instant) => instant.format(UTC_FORMAT).to_string(),
+        }
+    }
+
+    /// All-day dues start at midnight in `tz`.
+    pub fn instant(&self, tz: ServerTimezone) -> Option<DateTime<Utc>> {
+        match self {
+            Self::AllDay(date) => tz.from_local(date.and_time(NaiveTime::MIN)),
+            Self::At(instant) => Some(*instant),
+        }
+    }
+
+    pub fn local_date(&self, tz: ServerTimezone) -> NaiveDate {
+        match self {
+            Self::AllDay(date) => *date,
+            Self::At(instant) => tz.to_local(*instant).date_naive(),
+        }
+    }
+
+    pub fn is_all_day(&self) -> bool {
+        matches!(self, Self::AllDay(_))
+    }
+}
+
+/// Normalised view of a `dueDate` string from a backend, or `None` if it is not a date.
+pub fn due_date(original: &str, tz: ServerTimezone) -> Option<DueDate> {
+    let due = Due::parse(original, tz).ok()?;
+    let instant = due.instant(tz)?;
+    Some(DueDate {
+        original: original.to_owned(),
+        utc: instant.format(UTC_FORMAT).to_string(),
+        local: tz.to_local(instant).to_rfc3339(),
+        date: due.local_date(tz).format(DATE_FORMAT).to_string(),
+        all_day: due.is_all_day(),
+        timezone: tz.to_string(),
+        input: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn madrid() -> ServerTimezone {
+        ServerTimezone::Named(chrono_tz::Europe::Madrid)
+    }
+
+    #[test]
+    fn parses_all_day_offset_and_wall_clock_inputs() -> Result<(), AppError> {
+        assert_eq!(
+            Due::parse("2026-03-01", madrid())?.remindctl_arg(),
+            "2026-03-01"
+        );
+        assert_eq!(
+            Due::parse("2026-03-01T14:30:00+02:00", madrid())?.remindctl_arg(),
+            "2026-03-01T12:30:00Z"
+        );
+        assert_eq!(
+            Due::parse("2026-07-01T09:00", madrid())?.remindctl_arg(),
+            "2026-07-01T07:00:00Z"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_garbage_and_skipped_times_on_due() {
+        for input in ["tomorrow-ish", "2026-02-30", "2026-03-29T02:30"] {
+            let err = Due::parse(input, madrid()).err();
+            assert_eq!(
+                err.as_ref().and_then(AppError::field),
+                Some("due"),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn due_date_reports_utc_local_and_calendar_day() {
+        let timed = due_date("2026-03-01T23:30:00Z", madrid());
+        assert_eq!(
+            timed
+                .as_ref()
+                .map(|due| (due.local.as_str(), due.date.as_str())),
+            Some(("2026-03-02T00:30:00+01:00", "2026-03-02"))
+        );
+
+        let all_day = due_date("2026-03-01", madrid());
+        assert_eq!(
+            all_day.as_ref().map(|due| (due.utc.as_str(), due.all_day)),
+            Some(("2026-02-28T23:00:00Z", true))
+        );
+        assert!(due_date("soon", madrid()).is_none());
+    }
+}
@@ -0,0 +1,347 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::backend::ReminderBackend;
+use crate::config::{BackendKind, Config, FixtureMode};
+use crate::remindctl::RemindctlRunner;
+use crate::schema::SchemaDrift;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Outcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "PASS",
+            Self::Warn => "WARN",
+            Self::Fail => "FAIL",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub name: &'static str,
+    pub outcome: Outcome,
+    pub detail: String,
+    pub fix: Option<String>,
+}
+
+/// Result of `remindctl-mcp doctor`: one line per check, with a fix for anything
+/// that did not pass.
+#[derive(Debug, Default)]
+pub struct DoctorReport {
+    pub checks: Vec<Check>,
+}
+
+impl DoctorReport {
+    pub fn passed(&self) -> bool {
+        !self
+            .checks
+            .iter()
+            .any(|check| check.outcome == Outcome::Fail)
+    }
+
+    fn push(&mut self, name: &'static str, outcome: Outcome, detail: impl Into<String>) {
+        self.checks.push(Check {
+            name,
+            outcome,
+            detail: detail.into(),
+            fix: None,
+        });
+    }
+
+    fn push_with_fix(
+        &mut self,
+        name: &'static str,
+        outcome: Outcome,
+        detail: impl Into<String>,
+        fix: impl Into<String>,
+    ) {
+        self.checks.push(Check {
+            name,
+            outcome,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        });
+    }
+
+    fn count(&self, outcome: Outcome) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.outcome == outcome)
+            .count()
+    }
+}
+
+impl fmt::Display for DoctorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "remindctl-mcp doctor")?;
+        for check in &self.checks {
+            writeln!(
+                f,
+                "  [{}] {}: {}",
+                check.outcome.as_str(),
+                check.name,
+                check.detail
+            )?;
+            if let Some(fix) = &check.fix {
+                writeln!(f, "         fix: {fix}")?;
+            }
+        }
+        writeln!(
+            f,
+            "{} passed, {} warnings, {} failed",
+            self.count(Outcome::Pass),
+            self.count(Outcome::Warn),
+            self.count(Outcome::Fail)
+        )
+    }
+}
+
+/// Validates the host setup from the environment, the same way the server would start.
+pub async fn run() -> DoctorReport {
+    let mut report = DoctorReport::default();
+
+    let config = match Config::from_env() {
+        Ok(config) => {
+            report.push("config", Outcome::Pass, "environment parsed");
+            config
+        }
+        Err(err) => {
+            report.push_with_fix(
+                "config",
+                Outcome::Fail,
+                err.to_string(),
+                "fix the variable named above; see \"Environment variables\" in README.md",
+            );
+            return report;
+        }
+    };
+
+    check_exposure(&config, &mut report);
+    check_bind(&config, &mut report).await;
+
+    if config.backend == BackendKind::Memory {
+        report.push(
+            "backend",
+            Outcome::Warn,
+            "BACKEND=memory, skipping remindctl checks; data is not persisted",
+        );
+        return report;
+    }
+    if config.fixture_mode != FixtureMode::Off {
+        report.push(
+            "fixtures",
+            Outcome::Warn,
+            format!(
+                "REMINDCTL_FIXTURE_MODE={}, the server will not behave like production",
+                config.fixture_mode.as_str()
+            ),
+        );
+    }
+
+    check_remindctl(&config, &mut report).await;
+    report
+}
+
+fn check_exposure(config: &Config, report: &mut DoctorReport) {
+    let loopback = config.bind_addr.ip().is_loopback();
+    match (config.auth_required, loopback) {
+        (false, false) => report.push_with_fix(
+            "exposure",
+            Outcome::Fail,
+            format!(
+                "AUTH_REQUIRED=false while listening on non-loopback {}",
+                config.bind_addr
+            ),
+            "set AUTH_REQUIRED=true with an API_KEY, or bind to 127.0.0.1",
+        ),
+        (false, true) => report.push(
+            "exposure",
+            Outcome::Warn,
+            "AUTH_REQUIRED=false; any local process can manage reminders",
+        ),
+        (true, false) => report.push(
+            "exposure",
+            Outcome::Warn,
+            format!(
+                "listening on non-loopback {}; keep it behind a tunnel or tailnet",
+                config.bind_addr
+            ),
+        ),
+        (true, true) => report.push("exposure", Outcome::Pass, "API key auth on loopback"),
+    }
+}
+
+async fn check_bind(config: &Config, report: &mut DoctorReport) {
+    match tokio::net::TcpListener::bind(config.bind_addr).await {
+        Ok(_) => report.push(
+            "bind",
+            Outcome::Pass,
+            format!("{} is available", config.bind_addr),
+        ),
+        Err(err) => report.push_with_fix(
+            "bind",
+            Outcome::Fail,
+            format!("cannot bind {}: {err}", config.bind_addr),
+            "stop whatever holds the port (a running service instance?) or change BIND_ADDR",
+        ),
+    }
+}
+
+async fn check_remindctl(config: &Config, report: &mut DoctorReport) {
+    let Some(path) = resolve_binary(&config.remindctl_bin) else {
+        report.push_with_fix(
+            "remindctl binary",
+            Outcome::Fail,
+            format!("`{}` not found or not executable", config.remindctl_bin),
+            "install remindctl or point REMINDCTL_BIN at its absolute path",
+        );
+        return;
+    };
+
+    let schema_drift = Arc::new(SchemaDrift::new());
+    let runner = RemindctlRunner::new(
+        path.display().to_string(),
+        config.read_timeout,
+        config.write_timeout,
+    )
+    .with_schema_drift(Arc::clone(&schema_drift));
+
+    let capabilities = runner.probe_capabilities().await.report();
+    match (&capabilities.version, capabilities.unsupported.is_empty()) {
+        (None, _) => report.push(
+            "remindctl binary",
+            Outcome::Warn,
+            format!(
+                "{} found, but its version and flags could not be probed",
+                path.display()
+            ),
+        ),
+        (Some(version), true) => report.push(
+            "remindctl binary",
+            Outcome::Pass,
+            format!("{} ({version})", path.display()),
+        ),
+        (Some(version), false) => report.push_with_fix(
+            "remindctl binary",
+            Outcome::Warn,
+            format!(
+                "{} ({version}) lacks: {}",
+                path.display(),
+                capabilities.unsupported.join(", ")
+            ),
+            "upgrade remindctl; tools needing these flags will fail with `unsupported`",
+        ),
+    }
+
+    match runner.status().await {
+        Ok(status) if status.authorized => report.push(
+            "authorization",
+            Outcome::Pass,
+            format!("remindctl status: {}", status.status),
+        ),
+        Ok(status) => report.push_with_fix(
+            "authorization",
+            Outcome::Fail,
+            format!("remindctl status: {}", status.status),
+            "run `remindctl status` in Terminal on the host and allow Reminders access \
+             (System Settings > Privacy & Security > Reminders)",
+        ),
+        Err(err) => report.push_with_fix(
+            "authorization",
+            Outcome::Fail,
+            err.to_string(),
+            "run `remindctl status --json --no-input` by hand and fix the error it prints",
+        ),
+    }
+
+    match runner.lists().await {
+        Ok(lists) => report.push(
+            "list output",
+            Outcome::Pass,
+            format!("parsed {} lists", lists.len()),
+        ),
+        Err(err) => report.push_with_fix(
+            "list output",
+            Outcome::Fail,
+            err.to_string(),
+            "remindctl output no longer matches models.rs; upgrade remindctl-mcp or pin remindctl",
+        ),
+    }
+
+    match runner.show("all", None).await {
+        Ok(reminders) => report.push(
+            "show all output",
+            Outcome::Pass,
+            format!("parsed {} reminders", reminders.len()),
+        ),
+        Err(err) => report.push_with_fix(
+            "show all output",
+            Outcome::Fail,
+            err.to_string(),
+            "remindctl output no longer matches models.rs; upgrade remindctl-mcp or pin remindctl",
+        ),
+    }
+
+    let drifted = schema_drift
+        .report()
+        .models
+        .into_iter()
+        .filter(|model| !model.unknown_keys.is_empty() || !model.missing_required_keys.is_empty())
+        .map(|model| {
+            let keys = model
+                .unknown_keys
+                .keys()
+                .map(|key| format!("+{key}"))
+                .chain(
+                    model
+                        .missing_required_keys
+                        .keys()
+                        .map(|key| format!("-{key}")),
+                )
+                .collect::<Vec<_>>();
+            format!("{} ({})", model.model, keys.join(", "))
+        })
+        .collect::<Vec<_>>();
+    if drifted.is_empty() {
+        report.push("schema", Outcome::Pass, "remindctl JSON matches the models");
+    } else {
+        report.push(
+            "schema",
+            Outcome::Warn,
+            format!("output drifted: {}", drifted.join("; ")),
+        );
+    }
+}
+
+/// Resolves `bin` like the runner's `PATH` lookup would, requiring an executable file.
+fn resolve_binary(bin: &str) -> Option<PathBuf> {
+    if bin.contains('/') {
+        let path = PathBuf::from(bin);
+        return is_executable(&path).then_some(path);
+    }
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(bin))
+        .find(|path| is_executable(path))
+}
+
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
@@ -1,8 +1,11 @@
 use std::io;
+use std::sync::Arc;
 
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+use crate::models::ErrorCandidate;
+
+#[derive(Debug, Clone, Error)]
 pub enum AppError {
     #[error("invalid configuration: {0}")]
     InvalidConfig(String),
@@ -10,17 +13,47 @@ pub enum AppError {
     #[error("invalid input: {0}")]
     InvalidInput(String),
 
+    #[error("invalid input: {message}")]
+    InvalidField { field: String, message: String },
+
+    #[error("reminder ref '{reference}' is ambiguous, {} candidates", candidates.len())]
+    AmbiguousRef {
+        reference: String,
+        candidates: Vec<ErrorCandidate>,
+    },
+
     #[error("command timed out")]
     CommandTimeout,
 
     #[error("command failed: {0}")]
     CommandFailed(String),
 
+    #[error("remindctl unavailable after repeated failures, retry in {retry_after_secs}s")]
+    CircuitOpen { retry_after_secs: u64 },
+
+    #[error("remindctl is not authorized for Reminders: {0}")]
+    NotAuthorized(String),
+
+    #[error("list not found: {0}")]
+    ListNotFound(String),
+
+    #[error("reminder not found: {0}")]
+    ReminderNotFound(String),
+
+    #[error("invalid date: {0}")]
+    InvalidDate(String),
+
+    #[error("remindctl busy: {0}")]
+    Busy(String),
+
+    #[error("unsupported by remindctl: {0}")]
+    Unsupported(String),
+
     #[error("command I/O failed: {0}")]
-    CommandIo(#[from] io::Error),
+    CommandIo(Arc<io::Error>),
 
     #[error("json parse failed: {0}")]
-    Json(#[from] serde_json::Error),
+    Json(Arc<serde_json::Error>),
 }
 
 impl AppError {
@@ -31,4 +64,70 @@ impl AppError {
     pub fn invalid_input(message: impl Into<String>) -> Self {
         Self::InvalidInput(message.into())
     }
+
+    pub fn invalid_field(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::InvalidField {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Stable machine-readable code, exposed in tool errors and batch results.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidConfig(_) => "invalid_config",
+            Self::InvalidInput(_) | Self::InvalidField { .. } => "invalid_input",
+            Self::AmbiguousRef { .. } => "ambiguous_ref",
+            Self::CommandTimeout => "timeout",
+            Self::CommandFailed(_) => "command_failed",
+            Self::CircuitOpen { .. } => "circuit_open",
+            Self::NotAuthorized(_) => "not_authorized",
+            Self::ListNotFound(_) => "list_not_found",
+            Self::ReminderNotFound(_) => "reminder_not_found",
+            Self::InvalidDate(_) => "invalid_date",
+            Self::Busy(_) => "busy",
+            Self::Unsupported(_) => "unsupported",
+            Self::CommandIo(_) => "io",
+            Self::Json(_) => "json",
+        }
+    }
+
+    /// Input field the error is about, using the tool's wire name. `InvalidDate` comes
+    /// from remindctl, which does not say whether `due`, an alarm or a recurrence end
+    /// was rejected, so it names no field.
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::InvalidField { field, .. } => Some(field),
+            _ => None,
+        }
+    }
+
+    pub fn candidates(&self) -> &[ErrorCandidate] {
+        match self {
+            Self::AmbiguousRef { candidates, .. } => candidates,
+            _ => &[],
+        }
+    }
+
+    /// Failures the runner retries in place: the same call may succeed moments later.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::CommandTimeout | Self::Busy(_))
+    }
+
+    /// Whether a client should retry the same request later.
+    pub fn is_retryable(&self) -> bool {
+        self.is_transient() || matches!(self, Self::CircuitOpen { .. })
+    }
+}
+
+impl From<io::Error> for AppError {
+    fn from(error: io::Error) -> Self {
+        Self::CommandIo(Arc::new(error))
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(Arc::new(error))
+    }
 }
@@ -0,0 +1,158 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// One remindctl invocation as captured on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedCall {
+    pub args: Vec<String>,
+    pub status: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl RecordedCall {
+    pub fn success(&self) -> bool {
+        self.status == Some(0)
+    }
+}
+
+/// Record or replay remindctl invocations instead of (or in addition to) spawning the binary.
+#[derive(Debug)]
+pub enum Fixtures {
+    Record(FixtureRecorder),
+    Replay(FixtureReplayer),
+}
+
+/// Writes every call as `NNNN-<command>.json` in the fixture directory, in call order.
+#[derive(Debug)]
+pub struct FixtureRecorder {
+    dir: PathBuf,
+    next_seq: AtomicUsize,
+}
+
+impl FixtureRecorder {
+    pub fn new(dir: &Path) -> Result<Self, AppError> {
+        fs::create_dir_all(dir)?;
+        let existing = fixture_files(dir)?.len();
+        Ok(Self {
+            dir: dir.to_owned(),
+            next_seq: AtomicUsize::new(existing + 1),
+        })
+    }
+
+    pub fn record(&self, call: &RecordedCall) -> Result<(), AppError> {
+        let seq = self.next_seq.fetch_add(1, Ordering::SeqCst);
+        let command = call
+            .args
+            .first()
+            .map(|arg| sanitize_file_component(arg))
+            .unwrap_or_else(|| "call".to_owned());
+        let path = self.dir.join(format!("{seq:04}-{command}.json"));
+        fs::write(path, serde_json::to_vec_pretty(call)?)?;
+        Ok(())
+    }
+}
+
+/// Answers calls from recorded fixtures. Repeated identical argv are served in
+/// recording order; once exhausted, the last recording keeps answering.
+#[derive(Debug)]
+pub struct FixtureReplayer {
+    calls: Mutex<Vec<(RecordedCall, bool)>>,
+}
+
+impl FixtureReplayer {
+    pub fn load(dir: &Path) -> Result<Self, AppError> {
+        let mut calls = Vec::new();
+        for path in fixture_files(dir)? {
+            let raw = fs::read(&path)?;
+            calls.push((serde_json::from_slice::<RecordedCall>(&raw)?, false));
+        }
+        Ok(Self {
+            calls: Mutex::new(calls),
+        })
+    }
+
+    pub fn replay(&self, args: &[String]) -> Result<RecordedCall, AppError> {
+        let mut calls = self
+            .calls
+            .lock()
+            .map_err(|_| AppError::CommandFailed("fixture replay lock poisoned".to_owned()))?;
+
+        if let Some((call, served)) = calls
+            .iter_mut()
+            .find(|(call, served)| !served && call.args == args)
+        {
+            *served = true;
+            return Ok(call.clone());
+        }
+
+        calls
+            .iter()
+            .rev()
+            .find(|(call, _)| call.args == args)
+            .map(|(call, _)| call.clone())
+            .ok_or_else(|| {
+                AppError::CommandFailed(format!(
+                    "no recorded fixture for remindctl {}",
+                    args.join(" ")
+                ))
+            })
+    }
+}
+
+fn fixture_files(dir: &Path) -> Result<Vec<PathBuf>, AppError> {
+    let mut files = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+fn sanitize_file_component(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_call(args: &[&str], stdout: &str) -> RecordedCall {
+        RecordedCall {
+            args: args.iter().map(|arg| (*arg).to_owned()).collect(),
+            status: Some(0),
+            stdout: stdout.to_owned(),
+            stderr: String::new(),
+        }
+    }
+
+    #[test]
+    fn replays_identical_calls_in_recorded_order() -> Result<(), AppError> {
+        let dir = std::env::temp_dir().join(format!("remindctl-fixtures-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let recorder = FixtureRecorder::new(&dir)?;
+        recorder.record(&mk_call(&["show", "all"], "[1]"))?;
+        recorder.record(&mk_call(&["delete", "A"], "[]"))?;
+        recorder.record(&mk_call(&["show", "all"], "[]"))?;
+
+        let replayer = FixtureReplayer::load(&dir)?;
+        let show = vec!["show".to_owned(), "all".to_owned()];
+        assert_eq!(replayer.replay(&show)?.stdout, "[1]");
+        assert_eq!(replayer.replay(&show)?.stdout, "[]");
+        assert_eq!(replayer.replay(&show)?.stdout, "[]");
+        assert!(replayer.replay(&["list".to_owned()]).is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+pub mod alarms;
+pub mod backend;
+pub mod cache;
+pub mod capabilities;
+pub mod config;
+pub mod dates;
+pub mod doctor;
+pub mod error;
+pub mod fixtures;
+pub mod memory;
+pub mod models;
+pub mod paging;
+pub mod phrases;
+pub mod priority;
+pub mod projection;
+pub mod query;
+pub mod recurrence;
+pub mod remindctl;
+pub mod resilience;
+pub mod resolve;
+pub mod schema;
+pub mod search;
+pub mod server;
+pub mod text;
@@ -1,55 +1,35 @@
-mod config;
-mod error;
-mod models;
-mod remindctl;
-mod resolve;
-mod server;
-
+use std::process::ExitCode;
 use std::sync::Arc;
 
 use anyhow::Result;
-use axum::Router;
-use axum::middleware;
-use config::Config;
-use rmcp::transport::StreamableHttpServerConfig;
-use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
-use rmcp::transport::streamable_http_server::tower::StreamableHttpService;
-use server::{AppServer, RuntimeState, auth_middleware};
+use remindctl_mcp::config::Config;
+use remindctl_mcp::doctor;
+use remindctl_mcp::server::{RuntimeState, router};
 use tokio_util::sync::CancellationToken;
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> Result<ExitCode> {
     tracing_subscriber::fmt()
         .with_writer(std::io::stderr)
         .with_ansi(false)
         .init();
 
+    if std::env::args().nth(1).as_deref() == Some("doctor") {
+        let report = doctor::run().await;
+        print!("{report}");
+        return Ok(if report.passed() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
+
     let config = Config::from_env()?;
     config.log_startup();
 
-    let state = Arc::new(RuntimeState::new(config)?);
+    let state = Arc::new(RuntimeState::new(config).await?);
     let shutdown = CancellationToken::new();
-
-    let mcp_service: StreamableHttpService<AppServer, LocalSessionManager> =
-        StreamableHttpService::new(
-            {
-                let state = Arc::clone(&state);
-                move || Ok(AppServer::new(Arc::clone(&state)))
-            },
-            LocalSessionManager::default().into(),
-            StreamableHttpServerConfig {
-                cancellation_token: shutdown.child_token(),
-                ..Default::default()
-            },
-        );
-
-    let app =
-        Router::new()
-            .nest_service("/mcp", mcp_service)
-            .layer(middleware::from_fn_with_state(
-                Arc::clone(&state),
-                auth_middleware,
-            ));
+    let app = router(Arc::clone(&state), shutdown.child_token());
 
     let listener = tokio::net::TcpListener::bind(state.config.bind_addr).await?;
     tracing::info!(addr = %state.config.bind_addr, "mcp server listening");
@@ -63,5 +43,5 @@ async fn main() -> Result<()> {
         })
         .await?;
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
 }
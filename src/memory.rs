@@ -0,0 +1,591 @@
+use std::path::Path;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use serde::Deserialize;
+
+use crate::backend::{NewReminder, ReminderBackend, ReminderUpdate};
+use crate::dates::{Due, ServerTimezone};
+use crate::error::AppError;
+use crate::models::{RemindctlStatus, Reminder, ReminderList};
+
+const DEFAULT_LIST_NAME: &str = "Reminders";
+
+/// Seed data in the same JSON shape remindctl prints for `list` and `show all`.
+#[derive(Debug, Default, Deserialize)]
+struct MemoryFixture {
+    #[serde(default)]
+    lists: Vec<ReminderList>,
+    #[serde(default)]
+    reminders: Vec<Reminder>,
+}
+
+#[derive(Debug)]
+struct MemoryStore {
+    lists: Vec<ReminderList>,
+    reminders: Vec<Reminder>,
+    next_seq: u64,
+}
+
+/// Process-local reminder store that mimics remindctl semantics. Nothing is persisted.
+#[derive(Debug)]
+pub struct MemoryBackend {
+    store: Mutex<MemoryStore>,
+}
+
+impl MemoryBackend {
+    pub(crate) fn new() -> Self {
+        let mut store = MemoryStore {
+            lists: Vec::new(),
+            reminders: Vec::new(),
+            next_seq: 1,
+        };
+        store.create_list(DEFAULT_LIST_NAME);
+        Self {
+            store: Mutex::new(store),
+        }
+    }
+
+    pub fn from_fixture_file(path: &Path) -> Result<Self, AppError> {
+        let raw = std::fs::read(path)?;
+        let fixture = serde_json::from_slice::<MemoryFixture>(&raw)?;
+        Ok(Self::from_fixture(fixture))
+    }
+
+    fn from_fixture(fixture: MemoryFixture) -> Self {
+        let mut store = MemoryStore {
+            lists: fixture.lists,
+            reminders: Vec::new(),
+            next_seq: 1,
+        };
+        for reminder in fixture.reminders {
+            if !store.lists.iter().any(|list| list.id == reminder.list_id) {
+                store.lists.push(ReminderList {
+                    id: reminder.list_id.clone(),
+                    title: reminder.list_name.clone(),
+                    reminder_count: None,
+                    overdue_count: None,
+                });
+            }
+            store.reminders.push(reminder);
+        }
+        if store.lists.is_empty() {
+            store.create_list(DEFAULT_LIST_NAME);
+        }
+        Self {
+            store: Mutex::new(store),
+        }
+    }
+
+    fn with_store<T>(
+        &self,
+        f: impl FnOnce(&mut MemoryStore) -> Result<T, AppError>,
+    ) -> Result<T, AppError> {
+        let mut store = self
+            .store
+            .lock()
+            .map_err(|_| AppError::CommandFailed("memory store lock poisoned".to_owned()))?;
+        f(&mut store)
+    }
+}
+
+impl MemoryStore {
+    fn next_id(&mut self) -> String {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        synthetic_uuid(seq)
+    }
+
+    fn create_list(&mut self, name: &str) -> ReminderList {
+        let list = ReminderList {
+            id: self.next_id(),
+            title: name.to_owned(),
+            reminder_count: None,
+            overdue_count: None,
+        };
+        self.lists.push(list.clone());
+        list
+    }
+
+    fn list_by_name(&self, name: &str) -> Result<&ReminderList, AppError> {
+        self.lists
+            .iter()
+            .find(|list| list.title == name)
+            .ok_or_else(|| AppError::ListNotFound(name.to_owned()))
+    }
+
+    fn default_list(&self) -> Result<&ReminderList, AppError> {
+        self.lists
+            .iter()
+            .find(|list| list.title == DEFAULT_LIST_NAME)
+            .or_else(|| self.lists.first())
+            .ok_or_else(|| AppError::CommandFailed("No reminder lists available".to_owned()))
+    }
+
+    fn reminder_index(&self, id: &str) -> Result<usize, AppError> {
+        self.reminders
+            .iter()
+            .position(|reminder| reminder.id == id)
+            .ok_or_else(|| AppError::ReminderNotFound(id.to_owned()))
+    }
+}
+
+#[async_trait]
+impl ReminderBackend for MemoryBackend {
+    async fn status(&self) -> Result<RemindctlStatus, AppError> {
+        Ok(RemindctlStatus {
+            authorized: true,
+            status: "authorized".to_owned(),
+        })
+    }
+
+    async fn lists(&self) -> Result<Vec<ReminderList>, AppError> {
+        let now = Utc::now();
+        self.with_store(|store| {
+            Ok(store
+                .lists
+                .iter()
+                .map(|list| {
+                    let pending = store
+                        .reminders
+                        .iter()
+                        .filter(|reminder| reminder.list_id == list.id && !reminder.is_completed);
+                    ReminderList {
+                        id: list.id.clone(),
+                        title: list.title.clone(),
+                        reminder_count: Some(pending.clone().count() as i64),
+                        overdue_count: Some(
+                            pending
+                                .filter(|reminder| {
+                                    matches_filter(reminder, "overdue", now, ServerTimezone::Local)
+                                })
+                                .count() as i64,
+                        ),
+                    }
+                })
+                .collect())
+        })
+    }
+
+    async fn show(&self, filter: &str, list_name: Option<&str>) -> Result<Vec<Reminder>, AppError> {
+        let now = Utc::now();
+        let filter = filter.to_ascii_lowercase();
+        if !is_known_filter(&filter) {
+            return Err(AppError::CommandFailed(format!("Unknown filter: {filter}")));
+        }
+        self.with_store(|store| {
+            let list_id = match list_name {
+                Some(name) => Some(store.list_by_name(name)?.id.clone()),
+                None => None,
+            };
+            Ok(store
+                .reminders
+                .iter()
+                .filter(|reminder| list_id.as_ref().is_none_or(|id| &reminder.list_id == id))
+                .filter(|reminder| matches_filter(reminder, &filter, now, ServerTimezone::Local))
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn add(&self, reminder: NewReminder) -> Result<Reminder, AppError> {
+        let due_date = reminder.due.as_deref().map(normalize_due).transpose()?;
+        self.with_store(|store| {
+            let list = match reminder.list_name.as_deref() {
+                Some(name) => store.list_by_name(name)?,
+                None => store.default_list()?,
+            };
+            let (list_id, list_name) = (list.id.clone(), list.title.clone());
+            let now = timestamp_now();
+            let created = Reminder {
+                id: store.next_id(),
+                title: reminder.title,
+                list_id,
+                list_name,
+                is_completed: false,
+                priority: reminder.priority.unwrap_or_default(),
+                due_date,
+                notes: reminder.notes.unwrap_or_default(),
+                recurrence: reminder.recurrence,
+                alarms: (!reminder.alarms.is_empty()).then_some(reminder.alarms),
+                creation_date: Some(now.clone()),
+                last_modified_date: Some(now),
+                ..Reminder::default()
+            };
+            store.reminders.push(created.clone());
+            Ok(created)
+        })
+    }
+
+    async fn edit(&self, id: &str, update: ReminderUpdate) -> Result<Reminder, AppError> {
+        let due_date = update.due.as_deref().map(normalize_due).transpose()?;
+        self.with_store(|store| {
+            let target_list = match update.list_name.as_deref() {
+                Some(name) => {
+                    let list = store.list_by_name(name)?;
+                    Some((list.id.clone(), list.title.clone()))
+                }
+                None => None,
+            };
+            let index = store.reminder_index(id)?;
+            let reminder = &mut store.reminders[index];
+            if let Some(title) = update.title {
+                reminder.title = title;
+            }
+            if let Some((list_id, list_name)) = target_list {
+                reminder.list_id = list_id;
+                reminder.list_name = list_name;
+            }
+            if update.clear_due {
+                reminder.due_date = None;
+            }
+            if due_date.is_some() {
+                reminder.due_date = due_date;
+            }
+            if let Some(notes) = update.notes {
+                reminder.notes = notes;
+            }
+            if let Some(priority) = update.priority {
+                reminder.priority = priority;
+            }
+            if update.clear_recurrence {
+                reminder.recurrence = None;
+            }
+            if update.recurrence.is_some() {
+                reminder.recurrence = update.recurrence;
+            }
+            if update.clear_alarms {
+                reminder.alarms = None;
+            }
+            if !update.alarms.is_empty() {
+                reminder
+                    .alarms
+                    .get_or_insert_with(Vec::new)
+                    .extend(update.alarms);
+            }
+            match update.complete {
+                Some(true) => complete_or_advance(reminder),
+                Some(false) => set_completed(reminder, false),
+                None => {}
+            }
+            reminder.last_modified_date = Some(timestamp_now());
+            Ok(reminder.clone())
+        })
+    }
+
+    async fn complete(&self, ids: &[String], dry_run: bool) -> Result<Vec<Reminder>, AppError> {
+        self.with_store(|store| {
+            let mut completed = Vec::with_capacity(ids.len());
+            for id in ids {
+                let index = store.reminder_index(id)?;
+                let mut reminder = store.reminders[index].clone();
+                complete_or_advance(&mut reminder);
+                if !dry_run {
+                    store.reminders[index] = reminder.clone();
+                }
+                completed.push(reminder);
+            }
+            Ok(completed)
+        })
+    }
+
+    async fn delete(&self, ids: &[String], dry_run: bool) -> Result<Vec<Reminder>, AppError> {
+        self.with_store(|store| {
+            let mut deleted = Vec::with_capacity(ids.len());
+            for id in ids {
+                let index = store.reminder_index(id)?;
+                deleted.push(store.reminders[index].clone());
+            }
+            if !dry_run {
+                store
+                    .reminders
+                    .retain(|reminder| !ids.iter().any(|id| &reminder.id == id));
+            }
+            Ok(deleted)
+        })
+    }
+
+    async fn list_create(&self, name: &str) -> Result<(), AppError> {
+        self.with_store(|store| {
+            if store.list_by_name(name).is_ok() {
+                return Err(AppError::CommandFailed(format!(
+                    "List already exists: {name}"
+                )));
+            }
+            store.create_list(name);
+            Ok(())
+        })
+    }
+
+    async fn list_rename(&self, name: &str, new_name: &str) -> Result<(), AppError> {
+        self.with_store(|store| {
+            if store.list_by_name(new_name).is_ok() {
+                return Err(AppError::CommandFailed(format!(
+                    "List already exists: {new_name}"
+                )));
+            }
+            let list_id = store.list_by_name(name)?.id.clone();
+            for list in store.lists.iter_mut().filter(|list| list.id == list_id) {
+                list.title = new_name.to_owned();
+            }
+            for reminder in store
+                .reminders
+                .iter_mut()
+                .filter(|reminder| reminder.list_id == list_id)
+            {
+                reminder.list_name = new_name.to_owned();
+            }
+            Ok(())
+        })
+    }
+
+    async fn list_delete(&self, name: &str) -> Result<(), AppError> {
+        self.with_store(|store| {
+            let list_id = store.list_by_name(name)?.id.clone();
+            store.lists.retain(|list| list.id != list_id);
+            store
+                .reminders
+                .retain(|reminder| reminder.list_id != list_id);
+            Ok(())
+        })
+    }
+}
+
+/// Deterministic UUID-shaped ID, so fixtures and demos replay with the same references.
+fn synthetic_uuid(seq: u64) -> String {
+    let mut state = seq.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let mut next = || {
+        state ^= state >> 30;
+        state = state.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        state ^= state >> 27;
+        state = state.wrapping_mul(0x94D0_49BB_1331_11EB);
+        state ^= state >> 31;
+        state
+    };
+    let (high, low) = (next(), next());
+    format!(
+        "{:08X}-{:04X}-4{:03X}-A{:03X}-{:012X}",
+        high >> 32,
+        (high >> 16) & 0xFFFF,
+        high & 0xFFF,
+        low >> 52,
+        low & 0xFFFF_FFFF_FFFF
+    )
+}
+
+fn is_known_filter(filter: &str) -> bool {
+    matches!(
+        filter,
+        "today" | "tomorrow" | "week" | "overdue" | "upcoming" | "completed" | "all"
+    ) || parse_filter_date(filter, ServerTimezone::Local).is_some()
+}
+
+/// Applies a `show` filter with days counted in `tz`.
+fn matches_filter(
+    reminder: &Reminder,
+    filter: &str,
+    now: DateTime<Utc>,
+    tz: ServerTimezone,
+) -> bool {
+    if filter == "all" {
+        return true;
+    }
+    if filter == "completed" {
+        return reminder.is_completed;
+    }
+    if reminder.is_completed {
+        return false;
+    }
+
+    let Some(due) = reminder
+        .due_date
+        .as_deref()
+        .and_then(|value| Due::parse(value, tz).ok())
+    else {
+        return false;
+    };
+    let Some(instant) = due.instant(tz) else {
+        return false;
+    };
+    let day = due.local_date(tz);
+    let today = tz.to_local(now).date_naive();
+
+    match filter {
+        "today" => day == today,
+        "tomorrow" => today.succ_opt() == Some(day),
+        "week" => {
+            let end = today.checked_add_days(Days::new(7)).unwrap_or(today);
+            day >= today && day < end
+        }
+        "overdue" => instant < now,
+        "upcoming" => instant >= now,
+        other => parse_filter_date(other, tz) == Some(day),
+    }
+}
+
+fn parse_filter_date(value: &str, tz: ServerTimezone) -> Option<NaiveDate> {
+    Due::parse(value, tz).ok().map(|due| due.local_date(tz))
+}
+
+/// remindctl reads and filters dates in the host timezone, so the memory backend does too.
+fn parse_due(value: &str) -> Option<Due> {
+    Due::parse(value, ServerTimezone::Local).ok()
+}
+
+fn timestamp_now() -> String {
+    Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+fn set_completed(reminder: &mut Reminder, completed: bool) {
+    if reminder.is_completed != completed {
+        reminder.completion_date = completed.then(timestamp_now);
+        reminder.last_modified_date = Some(timestamp_now());
+    }
+    reminder.is_completed = completed;
+}
+
+/// Like Reminders, completing a recurring reminder moves its due date to the next
+/// occurrence and leaves it open; it only completes once the rule runs out.
+fn complete_or_advance(reminder: &mut Reminder) {
+    let current = reminder.due_date.as_deref().and_then(parse_due);
+    let next = reminder
+        .recurrence
+        .as_ref()
+        .zip(current)
+        .filter(|_| !reminder.is_completed)
+        .and_then(|(rule, due)| rule.advance(due, ServerTimezone::Local));
+    match next {
+        Some((due, rule)) => {
+            reminder.due_date = Some(due.remindctl_arg());
+            reminder.recurrence = Some(rule);
+            reminder.last_modified_date = Some(timestamp_now());
+        }
+        None => set_completed(reminder, true),
+    }
+}
+
+fn normalize_due(value: &str) -> Result<String, AppError> {
+    parse_due(value)
+        .map(|due| due.remindctl_arg())
+        .ok_or_else(|| AppError::InvalidDate(value.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::priority::Priority;
+    use crate::recurrence::Recurrence;
+
+    fn mk_reminder(id: &str, due: Option<&str>, completed: bool) -> Reminder {
+        Reminder {
+            id: id.to_owned(),
+            title: "x".to_owned(),
+            list_id: "L1".to_owned(),
+            list_name: "Reminders".to_owned(),
+            is_completed: completed,
+            priority: Priority::None,
+            due_date: due.map(str::to_owned),
+            ..Reminder::default()
+        }
+    }
+
+    #[test]
+    fn synthetic_ids_are_unique_and_not_numeric() {
+        let first = synthetic_uuid(1);
+        let second = synthetic_uuid(2);
+        assert_ne!(first[..4], second[..4]);
+        assert_eq!(first.len(), 36);
+        assert!(!first.chars().all(|ch| ch.is_ascii_digit()));
+    }
+
+    #[test]
+    fn filters_follow_remindctl_semantics() -> Result<(), String> {
+        let tz = ServerTimezone::Named(chrono_tz::Europe::Madrid);
+        let now = Utc
+            .with_ymd_and_hms(2026, 3, 10, 11, 0, 0)
+            .single()
+            .ok_or("fixed clock must be unambiguous")?;
+        let overdue = mk_reminder("A", Some("2026-03-09T08:00:00Z"), false);
+        let later_today = mk_reminder("B", Some("2026-03-10T17:00:00Z"), false);
+        let done = mk_reminder("C", Some("2026-03-09T08:00:00Z"), true);
+        let undated = mk_reminder("D", None, false);
+
+        assert!(matches_filter(&overdue, "overdue", now, tz));
+        assert!(!matches_filter(&done, "overdue", now, tz));
+        assert!(matches_filter(&later_today, "week", now, tz));
+        assert!(matches_filter(&later_today, "2026-03-10", now, tz));
+        assert!(!matches_filter(&undated, "upcoming", now, tz));
+        assert!(matches_filter(&done, "completed", now, tz));
+        assert!(matches_filter(&undated, "all", now, tz));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_complete_delete_round_trip() -> Result<(), AppError> {
+        let backend = MemoryBackend::new();
+        let created = backend
+            .add(NewReminder {
+                title: "Buy milk".to_owned(),
+                due: Some("2026-03-01".to_owned()),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(created.list_name, DEFAULT_LIST_NAME);
+
+        let completed = backend
+            .complete(std::slice::from_ref(&created.id), false)
+            .await?;
+        assert!(completed[0].is_completed);
+        assert!(backend.show("overdue", None).await?.is_empty());
+
+        backend.delete(&[created.id], false).await?;
+        assert!(backend.show("all", None).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn completing_a_recurring_reminder_advances_its_due() -> Result<(), AppError> {
+        let backend = MemoryBackend::new();
+        let created = backend
+            .add(NewReminder {
+                title: "Pay rent".to_owned(),
+                due: Some("2026-03-01".to_owned()),
+                recurrence: Some(Recurrence::parse_rrule(
+                    "FREQ=MONTHLY;COUNT=2",
+                    ServerTimezone::Local,
+                )?),
+                ..Default::default()
+            })
+            .await?;
+        let ids = std::slice::from_ref(&created.id);
+
+        let advanced = backend.complete(ids, false).await?;
+        assert!(!advanced[0].is_completed);
+        assert_eq!(advanced[0].due_date.as_deref(), Some("2026-04-01"));
+        assert_eq!(
+            advanced[0].recurrence.as_ref().and_then(|rule| rule.count),
+            Some(1)
+        );
+
+        let finished = backend.complete(ids, false).await?;
+        assert!(finished[0].is_completed);
+        assert_eq!(finished[0].due_date.as_deref(), Some("2026-04-01"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fixture_registers_lists_referenced_by_reminders() -> Result<(), AppError> {
+        let backend = MemoryBackend::from_fixture(MemoryFixture {
+            lists: Vec::new(),
+            reminders: vec![mk_reminder("AAAA-1111", None, false)],
+        });
+        let lists = backend.lists().await?;
+        assert_eq!(lists.len(), 1);
+        assert_eq!(lists[0].title, "Reminders");
+        assert_eq!(lists[0].reminder_count, Some(1));
+        Ok(())
+    }
+}
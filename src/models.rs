@@ -1,7 +1,14 @@
+use std::collections::BTreeMap;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+use crate::alarms::{self, Alarm};
+use crate::priority::{self, Priority};
+use crate::recurrence::{self, Recurrence};
+use crate::search::MatchKind;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Reminder {
     pub id: String,
     pub title: String,
@@ -11,11 +18,62 @@ pub struct Reminder {
     pub list_name: String,
     #[serde(rename = "isCompleted")]
     pub is_completed: bool,
-    pub priority: String,
+    #[serde(default, deserialize_with = "priority::deserialize_lenient")]
+    pub priority: Priority,
     #[serde(rename = "dueDate")]
     pub due_date: Option<String>,
+    /// `dueDate` normalised by the server. Never read from remindctl.
+    #[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub due: Option<DueDate>,
     #[serde(default)]
     pub notes: String,
+    #[serde(rename = "creationDate", default)]
+    pub creation_date: Option<String>,
+    #[serde(rename = "lastModifiedDate", default)]
+    pub last_modified_date: Option<String>,
+    #[serde(rename = "completionDate", default)]
+    pub completion_date: Option<String>,
+    #[serde(rename = "startDate", default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub flagged: Option<bool>,
+    /// Repeat rule, read from remindctl's RRULE string or structured form.
+    #[serde(
+        default,
+        deserialize_with = "recurrence::deserialize_lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub recurrence: Option<Recurrence>,
+    /// Alerts, as offsets from the due time or fixed instants.
+    #[serde(
+        default,
+        deserialize_with = "alarms::deserialize_lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub alarms: Option<Vec<Alarm>>,
+    /// Any other keys remindctl returns, kept so newer fields reach clients.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// A due date as remindctl reported it, plus the same moment in UTC and in the server
+/// timezone. All-day dues use midnight in the server timezone for `utc` and `local`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DueDate {
+    pub original: String,
+    pub utc: String,
+    pub local: String,
+    /// Calendar day in the server timezone, `YYYY-MM-DD`.
+    pub date: String,
+    #[serde(rename = "allDay")]
+    pub all_day: bool,
+    pub timezone: String,
+    /// The `due` this call was given, e.g. "mañana a las 9", so the resolved time can
+    /// be confirmed. Only set on `reminder_add`/`reminder_edit` results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -43,11 +101,108 @@ pub struct ServerHealth {
     pub remindctl_authorized: bool,
     #[serde(rename = "remindctlStatus")]
     pub remindctl_status: String,
+    pub cache: CacheStats,
+    pub breaker: BreakerStatus,
+    pub capabilities: CapabilityReport,
+}
+
+/// Keys seen in remindctl output that the models do not expect, or expected keys that
+/// were absent, with how often each happened.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SchemaDriftReport {
+    pub models: Vec<ModelDriftReport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ModelDriftReport {
+    pub model: String,
+    /// Objects of this model checked so far.
+    pub observed: u64,
+    #[serde(rename = "unknownKeys")]
+    pub unknown_keys: BTreeMap<String, u64>,
+    #[serde(rename = "missingRequiredKeys")]
+    pub missing_required_keys: BTreeMap<String, u64>,
+    #[serde(rename = "missingOptionalKeys")]
+    pub missing_optional_keys: BTreeMap<String, u64>,
+}
+
+/// Result of probing the installed `remindctl` at startup.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CapabilityReport {
+    pub version: Option<String>,
+    /// False when nothing could be probed; every flag is then assumed supported.
+    pub probed: bool,
+    pub supported: Vec<String>,
+    pub unsupported: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CacheStats {
+    #[serde(rename = "ttlSecs")]
+    pub ttl_secs: u64,
+    pub hits: u64,
+    pub misses: u64,
+    #[serde(rename = "hitRate")]
+    pub hit_rate: f64,
+    #[serde(rename = "listsAgeSecs")]
+    pub lists_age_secs: Option<u64>,
+    #[serde(rename = "remindersAgeSecs")]
+    pub reminders_age_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BreakerStatus {
+    /// `closed`, `open` (failing fast), or `half_open` (next call probes remindctl).
+    pub state: String,
+    #[serde(rename = "consecutiveFailures")]
+    pub consecutive_failures: u32,
+    #[serde(rename = "retryAfterSecs")]
+    pub retry_after_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct ReminderListResult {
     pub reminders: Vec<Reminder>,
+    /// Matches across all pages, when the read was sorted or paged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<usize>,
+    /// Pass back as `cursor` for the next page; absent on the last one.
+    #[serde(rename = "nextCursor", skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OccurrencesResult {
+    pub recurrence: Recurrence,
+    /// The same rule in RFC 5545 form.
+    pub rrule: String,
+    pub occurrences: Vec<DueDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchResult {
+    /// Matches before `limit` was applied.
+    pub total: usize,
+    pub results: Vec<SearchHit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchHit {
+    pub reminder: Reminder,
+    /// Higher is better; title matches weigh twice as much as notes matches.
+    pub score: f64,
+    pub highlights: Vec<Highlight>,
+}
+
+/// Where a query term matched. `start`/`end` count characters in the original field.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Highlight {
+    pub field: String,
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+    pub term: String,
+    pub kind: MatchKind,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -79,9 +234,50 @@ pub struct BatchActionResult {
     pub op: String,
     pub ok: bool,
     pub error: Option<String>,
+    /// Stable error code (see [`ToolError`]) when `ok` is false.
+    pub code: Option<String>,
+    pub retryable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub candidates: Vec<ErrorCandidate>,
     pub data: Option<serde_json::Value>,
 }
 
+/// A failed tool call: a stable `code` clients can branch on, the human message, and
+/// whether the same request may succeed if retried later.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ToolError {
+    pub code: String,
+    pub message: String,
+    pub retryable: bool,
+    /// Input field (wire name) the error is about, when there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// Reminders an ambiguous reference could mean; retry with one of their IDs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub candidates: Vec<ErrorCandidate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ErrorCandidate {
+    pub id: String,
+    pub title: String,
+    pub list: String,
+    pub due: Option<String>,
+}
+
+impl From<&Reminder> for ErrorCandidate {
+    fn from(reminder: &Reminder) -> Self {
+        Self {
+            id: reminder.id.clone(),
+            title: reminder.title.clone(),
+            list: reminder.list_name.clone(),
+            due: reminder.due_date.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BatchProcessResult {
     pub processed: i64,
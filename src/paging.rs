@@ -0,0 +1,340 @@
+use std::cmp::Ordering;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::dates::{Due, ServerTimezone};
+use crate::error::AppError;
+use crate::models::Reminder;
+use crate::text::fold;
+
+pub const MAX_LIMIT: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum SortBy {
+    /// Highest priority first by default.
+    Priority,
+    /// Earliest due first by default; undated reminders always come last.
+    Due,
+    /// Case- and accent-insensitive.
+    Title,
+    /// List name, then title.
+    List,
+    Created,
+    Modified,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortBy {
+    fn default_order(self) -> SortOrder {
+        match self {
+            Self::Priority => SortOrder::Desc,
+            _ => SortOrder::Asc,
+        }
+    }
+}
+
+/// How to order and cut one read. `scope` fingerprints everything else about the
+/// request, so a cursor cannot be replayed against a different filter.
+#[derive(Debug, Clone, Default)]
+pub struct PageRequest {
+    pub sort_by: Option<SortBy>,
+    pub order: Option<SortOrder>,
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+    pub scope: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Page {
+    pub reminders: Vec<Reminder>,
+    /// Matches across all pages.
+    pub total: usize,
+    pub next_cursor: Option<String>,
+}
+
+/// Where a reminder sorts. Missing values sort last in either order; `id` breaks ties
+/// so every reminder has a unique position a cursor can point at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct SortKey {
+    missing: bool,
+    number: i64,
+    text: String,
+    id: String,
+}
+
+impl SortKey {
+    fn of(reminder: &Reminder, sort_by: SortBy, tz: ServerTimezone) -> Self {
+        let timestamp = |value: Option<&str>| {
+            value
+                .and_then(|value| Due::parse(value, tz).ok())
+                .and_then(|due| due.instant(tz))
+                .map(|instant| instant.timestamp())
+        };
+        let (number, text) = match sort_by {
+            SortBy::Priority => (Some(reminder.priority as i64), String::new()),
+            SortBy::Due => (timestamp(reminder.due_date.as_deref()), String::new()),
+            SortBy::Title => (Some(0), fold(&reminder.title)),
+            SortBy::List => (
+                Some(0),
+                format!(
+                    "{}\u{0}{}",
+                    fold(&reminder.list_name),
+                    fold(&reminder.title)
+                ),
+            ),
+            SortBy::Created => (timestamp(reminder.creation_date.as_deref()), String::new()),
+            SortBy::Modified => (
+                timestamp(reminder.last_modified_date.as_deref()),
+                String::new(),
+            ),
+        };
+        Self {
+            missing: number.is_none(),
+            number: number.unwrap_or_default(),
+            text,
+            id: reminder.id.clone(),
+        }
+    }
+
+    fn compare(&self, other: &Self, order: SortOrder) -> Ordering {
+        let value = (self.number, &self.text).cmp(&(other.number, &other.text));
+        self.missing
+            .cmp(&other.missing)
+            .then(match order {
+                SortOrder::Asc => value,
+                SortOrder::Desc => value.reverse(),
+            })
+            .then_with(|| self.id.cmp(&other.id))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Cursor {
+    #[serde(rename = "s")]
+    sort_by: SortBy,
+    #[serde(rename = "o")]
+    order: SortOrder,
+    #[serde(rename = "f")]
+    scope: u64,
+    #[serde(rename = "k")]
+    after: SortKey,
+}
+
+impl Cursor {
+    fn encode(&self) -> String {
+        serde_json::to_vec(self)
+            .unwrap_or_default()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        let bytes = (0..value.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(value.get(index..index + 2)?, 16).ok())
+            .collect::<Option<Vec<_>>>()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+}
+
+/// Sorts and pages `reminders`. Without `sortBy`, `limit` or `cursor` the backend's order
+/// is kept as is; paging without `sortBy` orders by due date. A cursor resumes strictly
+/// after the last reminder it saw, so inserts and deletes elsewhere do not shift pages.
+pub fn paginate(
+    mut reminders: Vec<Reminder>,
+    request: &PageRequest,
+    tz: ServerTimezone,
+) -> Result<Page, AppError> {
+    if let Some(limit) = request.limit
+        && !(1..=MAX_LIMIT).contains(&limit)
+    {
+        return Err(AppError::invalid_field(
+            "limit",
+            format!("limit must be between 1 and {MAX_LIMIT}"),
+        ));
+    }
+    let paging = request.limit.is_some() || request.cursor.is_some();
+    let Some(sort_by) = request.sort_by.or(paging.then_some(SortBy::Due)) else {
+        let total = reminders.len();
+        return Ok(Page {
+            reminders,
+            total,
+            next_cursor: None,
+        });
+    };
+    let order = request.order.unwrap_or(sort_by.default_order());
+
+    let mut keyed = reminders
+        .drain(..)
+        .map(|reminder| (SortKey::of(&reminder, sort_by, tz), reminder))
+        .collect::<Vec<_>>();
+    keyed.sort_by(|(a, _), (b, _)| a.compare(b, order));
+    let total = keyed.len();
+
+    if let Some(cursor) = &request.cursor {
+        let cursor = Cursor::decode(cursor).ok_or_else(|| {
+            AppError::invalid_field("cursor", "cursor is not one this server issued")
+        })?;
+        if cursor.sort_by != sort_by || cursor.order != order || cursor.scope != request.scope {
+            return Err(AppError::invalid_field(
+                "cursor",
+                "cursor belongs to a different filter, query or sort; start again without it",
+            ));
+        }
+        keyed.retain(|(key, _)| key.compare(&cursor.after, order).is_gt());
+    }
+
+    let limit = request.limit.unwrap_or(keyed.len());
+    let next_cursor = (keyed.len() > limit).then(|| {
+        Cursor {
+            sort_by,
+            order,
+            scope: request.scope,
+            after: keyed[limit - 1].0.clone(),
+        }
+        .encode()
+    });
+    keyed.truncate(limit);
+    Ok(Page {
+        reminders: keyed.into_iter().map(|(_, reminder)| reminder).collect(),
+        total,
+        next_cursor,
+    })
+}
+
+/// FNV-1a over the request parts a cursor is tied to. Stable across restarts, unlike
+/// `DefaultHasher`.
+pub fn fingerprint(parts: &[&str]) -> u64 {
+    parts.iter().fold(0xcbf2_9ce4_8422_2325, |hash, part| {
+        part.bytes().chain([0xff]).fold(hash, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::priority::Priority;
+
+    fn reminder(id: &str, title: &str, due: Option<&str>) -> Reminder {
+        Reminder {
+            id: id.to_owned(),
+            title: title.to_owned(),
+            due_date: due.map(str::to_owned),
+            ..Reminder::default()
+        }
+    }
+
+    fn ids(page: &Page) -> Vec<&str> {
+        page.reminders
+            .iter()
+            .map(|reminder| reminder.id.as_str())
+            .collect()
+    }
+
+    fn utc() -> ServerTimezone {
+        ServerTimezone::Named(chrono_tz::UTC)
+    }
+
+    #[test]
+    fn cursors_resume_after_the_last_seen_reminder_despite_inserts() -> Result<(), AppError> {
+        let mut reminders = vec![
+            reminder("D", "d", None),
+            reminder("B", "b", Some("2026-03-02")),
+            reminder("A", "a", Some("2026-03-01")),
+            reminder("C", "c", Some("2026-03-03")),
+        ];
+        let first = paginate(
+            reminders.clone(),
+            &PageRequest {
+                limit: Some(2),
+                ..PageRequest::default()
+            },
+            utc(),
+        )?;
+        assert_eq!((ids(&first), first.total), (vec!["A", "B"], 4));
+
+        reminders.push(reminder("A0", "early", Some("2026-02-01")));
+        let second = paginate(
+            reminders,
+            &PageRequest {
+                limit: Some(2),
+                cursor: first.next_cursor,
+                ..PageRequest::default()
+            },
+            utc(),
+        )?;
+        assert_eq!(ids(&second), vec!["C", "D"]);
+        assert_eq!(second.next_cursor, None);
+        Ok(())
+    }
+
+    #[test]
+    fn orders_keep_missing_values_last_and_break_ties_by_id() -> Result<(), AppError> {
+        let mut high = reminder("B", "Beta", None);
+        high.priority = Priority::High;
+        let mut also_high = reminder("A", "alpha", None);
+        also_high.priority = Priority::High;
+        let page = paginate(
+            vec![reminder("C", "Çharlie", None), high, also_high],
+            &PageRequest {
+                sort_by: Some(SortBy::Priority),
+                ..PageRequest::default()
+            },
+            utc(),
+        )?;
+        assert_eq!(ids(&page), vec!["A", "B", "C"]);
+
+        let by_title = paginate(
+            page.reminders,
+            &PageRequest {
+                sort_by: Some(SortBy::Title),
+                order: Some(SortOrder::Desc),
+                ..PageRequest::default()
+            },
+            utc(),
+        )?;
+        assert_eq!(ids(&by_title), vec!["C", "B", "A"]);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_foreign_or_mangled_cursors() -> Result<(), AppError> {
+        let first = paginate(
+            vec![reminder("A", "a", None), reminder("B", "b", None)],
+            &PageRequest {
+                limit: Some(1),
+                scope: fingerprint(&["today"]),
+                ..PageRequest::default()
+            },
+            utc(),
+        )?;
+        for (cursor, scope) in [
+            (first.next_cursor.clone(), fingerprint(&["week"])),
+            (Some("zz".to_owned()), fingerprint(&["today"])),
+        ] {
+            let err = paginate(
+                Vec::new(),
+                &PageRequest {
+                    cursor,
+                    scope,
+                    ..PageRequest::default()
+                },
+                utc(),
+            )
+            .err();
+            assert_eq!(err.as_ref().and_then(AppError::field), Some("cursor"));
+        }
+        Ok(())
+    }
+}
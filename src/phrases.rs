@@ -0,0 +1,409 @@
+use chrono::{DateTime, Datelike, Days, Duration, NaiveDate, NaiveTime, Timelike, Utc, Weekday};
+
+use crate::dates::{Due, ServerTimezone};
+use crate::text;
+
+const WEEKDAYS: [(&str, Weekday); 14] = [
+    ("monday", Weekday::Mon),
+    ("lunes", Weekday::Mon),
+    ("tuesday", Weekday::Tue),
+    ("martes", Weekday::Tue),
+    ("wednesday", Weekday::Wed),
+    ("miercoles", Weekday::Wed),
+    ("thursday", Weekday::Thu),
+    ("jueves", Weekday::Thu),
+    ("friday", Weekday::Fri),
+    ("viernes", Weekday::Fri),
+    ("saturday", Weekday::Sat),
+    ("sabado", Weekday::Sat),
+    ("sunday", Weekday::Sun),
+    ("domingo", Weekday::Sun),
+];
+
+const NUMBERS: [(&str, u32); 24] = [
+    ("a", 1),
+    ("an", 1),
+    ("one", 1),
+    ("un", 1),
+    ("una", 1),
+    ("uno", 1),
+    ("two", 2),
+    ("dos", 2),
+    ("three", 3),
+    ("tres", 3),
+    ("four", 4),
+    ("cuatro", 4),
+    ("five", 5),
+    ("cinco", 5),
+    ("six", 6),
+    ("seis", 6),
+    ("seven", 7),
+    ("siete", 7),
+    ("eight", 8),
+    ("ocho", 8),
+    ("nine", 9),
+    ("nueve", 9),
+    ("ten", 10),
+    ("diez", 10),
+];
+
+/// Words that only introduce what follows: "on friday", "el lunes que viene".
+const FILLERS: [&str; 17] = [
+    "on", "the", "this", "next", "coming", "el", "este", "esta", "proximo", "proxima", "que",
+    "viene", "from", "now", "desde", "ahora", "por",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Meridiem {
+    Am,
+    Pm,
+}
+
+/// Resolves an English or Spanish due phrase ("tomorrow at 5pm", "in 2 hours",
+/// "mañana a las 9", "el lunes") against `now` in `tz`. A phrase with only a day
+/// is all-day; a time without a day means its next occurrence.
+pub fn resolve(text: &str, tz: ServerTimezone, now: DateTime<Utc>) -> Option<Due> {
+    let folded = fold_phrase(text);
+    let tokens = folded.split_whitespace().collect::<Vec<_>>();
+    let today = tz.to_local(now).date_naive();
+
+    let mut date = None::<NaiveDate>;
+    let mut time = None::<NaiveTime>;
+    let mut meridiem = None::<Meridiem>;
+    let mut instant = None::<DateTime<Utc>>;
+    let mut index = 0;
+
+    while let Some(&token) = tokens.get(index) {
+        index += 1;
+        let next = tokens.get(index).copied();
+        match token {
+            "today" | "hoy" => date = Some(today),
+            "tomorrow" | "manana" => date = today.checked_add_days(Days::new(1)),
+            "tonight" => {
+                date = Some(today);
+                time = time.or(NaiveTime::from_hms_opt(20, 0, 0));
+            }
+            "pasado" if next == Some("manana") => {
+                index += 1;
+                date = today.checked_add_days(Days::new(2));
+            }
+            "day" if tokens.get(index..index + 2) == Some(&["after", "tomorrow"]) => {
+                index += 2;
+                date = today.checked_add_days(Days::new(2));
+            }
+            "noon" | "mediodia" => time = NaiveTime::from_hms_opt(12, 0, 0),
+            "midnight" | "medianoche" => time = Some(NaiveTime::MIN),
+            "am" => meridiem = Some(Meridiem::Am),
+            "pm" => meridiem = Some(Meridiem::Pm),
+            "at" | "a" | "@" => {
+                if matches!(next, Some("las" | "la")) {
+                    index += 1;
+                }
+                match tokens.get(index).copied() {
+                    Some("noon" | "mediodia" | "midnight" | "medianoche") => {}
+                    Some(clock_token) => {
+                        let (parsed, suffix) = clock(clock_token)?;
+                        time = Some(parsed);
+                        meridiem = suffix.or(meridiem);
+                        index += 1;
+                    }
+                    None => return None,
+                }
+            }
+            // "la mañana" is the morning, a bare "mañana" is tomorrow.
+            "la" if next.and_then(part_of_day).is_some() => {
+                let (default, part) = part_of_day(next?)?;
+                index += 1;
+                meridiem = meridiem.or(Some(part));
+                time = time.or(Some(default));
+            }
+            "in" if next == Some("the") => {}
+            "in" | "en" | "within" | "dentro" => {
+                if token == "dentro" && next == Some("de") {
+                    index += 1;
+                }
+                let (amount, consumed) = relative(&tokens[index..])?;
+                index += consumed;
+                match amount {
+                    Amount::Minutes(minutes) => {
+                        instant = now.checked_add_signed(Duration::minutes(minutes))
+                    }
+                    Amount::Days(days) => date = today.checked_add_days(Days::new(days)),
+                }
+            }
+            "morning" | "afternoon" | "evening" | "night" | "tarde" | "noche" | "madrugada" => {
+                let (default, part) = part_of_day(token)?;
+                meridiem = meridiem.or(Some(part));
+                time = time.or(Some(default));
+            }
+            "de" if next == Some("la") => {}
+            other if FILLERS.contains(&other) => {}
+            other => {
+                if let Some(weekday) = weekday(other) {
+                    date = Some(next_weekday(today, weekday));
+                    continue;
+                }
+                let (parsed, suffix) = clock(other)?;
+                let has_marker = other.contains(':')
+                    || suffix.is_some()
+                    || matches!(next, Some("am" | "pm" | "h"));
+                if !has_marker {
+                    return None;
+                }
+                time = Some(parsed);
+                meridiem = suffix.or(meridiem);
+            }
+        }
+    }
+
+    if let Some(instant) = instant {
+        return (date.is_none() && time.is_none()).then_some(Due::At(instant));
+    }
+    let Some(time) = time else {
+        return date.map(Due::AllDay);
+    };
+    let time = apply_meridiem(time, meridiem)?;
+    let date = match date {
+        Some(date) => date,
+        None if tz
+            .from_local(today.and_time(time))
+            .is_some_and(|at| at > now) =>
+        {
+            today
+        }
+        None => today.checked_add_days(Days::new(1))?,
+    };
+    tz.from_local(date.and_time(time)).map(Due::At)
+}
+
+/// Minutes before a due time for an alarm offset: "15 minutes before", "1 hora antes",
+/// "half an hour", "15m", "2d". "after"/"después" or a leading `+` give a negative value;
+/// "at due time"/"a la hora" is zero.
+pub fn minutes_before(text: &str) -> Option<i64> {
+    let folded = fold_phrase(text);
+    let trimmed = folded.trim();
+    if matches!(
+        trimmed,
+        "0" | "at due time" | "on time" | "at time of event" | "a la hora" | "en punto"
+    ) {
+        return Some(0);
+    }
+    let (sign, body) = match trimmed.strip_prefix('+') {
+        Some(rest) => (-1, rest),
+        None => (1, trimmed.strip_prefix('-').unwrap_or(trimmed)),
+    };
+    let mut tokens = body.split_whitespace().collect::<Vec<_>>();
+    let sign = match tokens.last().copied() {
+        Some("before" | "early" | "earlier" | "antes") => {
+            tokens.pop();
+            sign
+        }
+        Some("after" | "later" | "despues") => {
+            tokens.pop();
+            -sign
+        }
+        _ => sign,
+    };
+    if let [compact] = tokens.as_slice() {
+        let split = compact.find(|ch: char| !ch.is_ascii_digit())?;
+        let (count, unit) = compact.split_at(split);
+        tokens = vec![count, unit];
+    }
+    let (amount, used) = relative(&tokens)?;
+    if used != tokens.len() {
+        return None;
+    }
+    let minutes = match amount {
+        Amount::Minutes(minutes) => minutes,
+        Amount::Days(days) => i64::try_from(days).ok()? * 24 * 60,
+    };
+    Some(sign * minutes)
+}
+
+enum Amount {
+    Minutes(i64),
+    Days(u64),
+}
+
+/// `N unit` after "in"/"en": "2 hours", "an hour", "half an hour", "media hora", "3 días".
+/// Returns the amount and how many tokens it used.
+fn relative(tokens: &[&str]) -> Option<(Amount, usize)> {
+    let first = *tokens.first()?;
+    if matches!(first, "half" | "media") {
+        let skip = usize::from(matches!(tokens.get(1).copied(), Some("an" | "a")));
+        let unit = *tokens.get(1 + skip)?;
+        return matches!(unit, "hour" | "hora").then_some((Amount::Minutes(30), 2 + skip));
+    }
+    let count = first.parse::<u32>().ok().or_else(|| {
+        NUMBERS
+            .iter()
+            .find(|(word, _)| *word == first)
+            .map(|(_, value)| *value)
+    })?;
+    let amount = match *tokens.get(1)? {
+        "minute" | "minutes" | "min" | "mins" | "m" | "minuto" | "minutos" => {
+            Amount::Minutes(i64::from(count))
+        }
+        "hour" | "hours" | "hr" | "hrs" | "h" | "hora" | "horas" => {
+            Amount::Minutes(i64::from(count) * 60)
+        }
+        "day" | "days" | "d" | "dia" | "dias" => Amount::Days(u64::from(count)),
+        "week" | "weeks" | "w" | "semana" | "semanas" => Amount::Days(u64::from(count) * 7),
+        _ => return None,
+    };
+    Some((amount, 2))
+}
+
+/// Default time and meridiem for "morning", "la tarde", "tonight"-style words.
+fn part_of_day(word: &str) -> Option<(NaiveTime, Meridiem)> {
+    let (hour, meridiem) = match word {
+        "morning" | "manana" => (9, Meridiem::Am),
+        "madrugada" => (3, Meridiem::Am),
+        "afternoon" | "tarde" => (15, Meridiem::Pm),
+        "evening" => (18, Meridiem::Pm),
+        "night" | "noche" => (20, Meridiem::Pm),
+        _ => return None,
+    };
+    Some((NaiveTime::from_hms_opt(hour, 0, 0)?, meridiem))
+}
+
+/// `5`, `17:30`, `5pm`, `9:15am`, `17h`.
+fn clock(token: &str) -> Option<(NaiveTime, Option<Meridiem>)> {
+    let (digits, meridiem) = if let Some(rest) = token.strip_suffix("am") {
+        (rest, Some(Meridiem::Am))
+    } else if let Some(rest) = token.strip_suffix("pm") {
+        (rest, Some(Meridiem::Pm))
+    } else {
+        (token.strip_suffix('h').unwrap_or(token), None)
+    };
+    let (hour, minute) = match digits.split_once(':') {
+        Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+        None => (digits.parse::<u32>().ok()?, 0),
+    };
+    if meridiem.is_some() && !(1..=12).contains(&hour) {
+        return None;
+    }
+    Some((NaiveTime::from_hms_opt(hour, minute, 0)?, meridiem))
+}
+
+fn apply_meridiem(time: NaiveTime, meridiem: Option<Meridiem>) -> Option<NaiveTime> {
+    let hour = match (meridiem, time.hour()) {
+        (Some(Meridiem::Pm), hour) if hour < 12 => hour + 12,
+        (Some(Meridiem::Am), 12) => 0,
+        (_, hour) => hour,
+    };
+    time.with_hour(hour)
+}
+
+fn weekday(word: &str) -> Option<Weekday> {
+    WEEKDAYS
+        .iter()
+        .find(|(name, _)| *name == word)
+        .map(|(_, weekday)| *weekday)
+}
+
+/// The next `weekday` after `today`; naming today's weekday means a week from now.
+fn next_weekday(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let ahead = (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    let ahead = if ahead == 0 { 7 } else { ahead };
+    today
+        .checked_add_days(Days::new(u64::from(ahead)))
+        .unwrap_or(today)
+}
+
+/// Folds and drops punctuation: "Mañana, a las 9 p.m." -> "manana a las 9 pm".
+fn fold_phrase(text: &str) -> String {
+    text::fold(text).replace([',', '.', '!', '?', '¿', '¡'], "")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn madrid() -> ServerTimezone {
+        ServerTimezone::Named(chrono_tz::Europe::Madrid)
+    }
+
+    /// Wednesday 2026-03-11, 10:00 in Madrid.
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 11, 9, 0, 0)
+            .single()
+            .unwrap_or_default()
+    }
+
+    fn resolved(text: &str) -> Option<String> {
+        resolve(text, madrid(), now()).map(|due| due.remindctl_arg())
+    }
+
+    #[test]
+    fn resolves_english_phrases() {
+        assert_eq!(
+            resolved("tomorrow at 5pm").as_deref(),
+            Some("2026-03-12T16:00:00Z")
+        );
+        assert_eq!(resolved("next Friday").as_deref(), Some("2026-03-13"));
+        assert_eq!(
+            resolved("in 2 hours").as_deref(),
+            Some("2026-03-11T11:00:00Z")
+        );
+        assert_eq!(
+            resolved("wednesday at 9:30 am").as_deref(),
+            Some("2026-03-18T08:30:00Z")
+        );
+        assert_eq!(resolved("at 8am").as_deref(), Some("2026-03-12T07:00:00Z"));
+        assert_eq!(resolved("in nine days").as_deref(), Some("2026-03-20"));
+    }
+
+    #[test]
+    fn resolves_spanish_phrases() {
+        assert_eq!(
+            resolved("mañana a las 9").as_deref(),
+            Some("2026-03-12T08:00:00Z")
+        );
+        assert_eq!(resolved("el lunes").as_deref(), Some("2026-03-16"));
+        assert_eq!(
+            resolved("el viernes a las 7 de la tarde").as_deref(),
+            Some("2026-03-13T18:00:00Z")
+        );
+        assert_eq!(
+            resolved("mañana por la mañana").as_deref(),
+            Some("2026-03-12T08:00:00Z")
+        );
+        assert_eq!(
+            resolved("dentro de media hora").as_deref(),
+            Some("2026-03-11T09:30:00Z")
+        );
+        assert_eq!(resolved("pasado mañana").as_deref(), Some("2026-03-13"));
+        assert_eq!(
+            resolved("dentro de nueve horas").as_deref(),
+            Some("2026-03-11T18:00:00Z")
+        );
+    }
+
+    #[test]
+    fn rejects_phrases_it_does_not_understand() {
+        for text in ["someday", "tomorrow 5", "in 2 fortnights", "at 25:00", ""] {
+            assert_eq!(resolved(text), None, "{text}");
+        }
+    }
+
+    #[test]
+    fn alarm_offsets_in_both_languages() {
+        let cases = [
+            ("15 minutes before", Some(15)),
+            ("1 hora antes", Some(60)),
+            ("half an hour", Some(30)),
+            ("2d", Some(2880)),
+            ("-1h", Some(60)),
+            ("10 min después", Some(-10)),
+            ("at due time", Some(0)),
+            ("15 lightyears", None),
+            ("tomorrow", None),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(minutes_before(text), expected, "{text}");
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::error::AppError;
+
+/// Reminder priority, ordered from `none` to `high`.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    #[default]
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+/// A priority as a client or remindctl wrote it: a name in English or Spanish, `!`
+/// to `!!!`, or an EventKit number (0 none, 1-4 high, 5 medium, 6-9 low).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum PriorityInput {
+    Number(i64),
+    Text(String),
+}
+
+impl Priority {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+
+    /// Maps a flexible input to a priority; `field` names it in the error.
+    pub fn parse(input: &PriorityInput, field: &str) -> Result<Self, AppError> {
+        let parsed = match input {
+            PriorityInput::Number(number) => Self::from_eventkit(*number),
+            PriorityInput::Text(text) => Self::from_text(text),
+        };
+        parsed.ok_or_else(|| {
+            let value = match input {
+                PriorityInput::Number(number) => number.to_string(),
+                PriorityInput::Text(text) => text.clone(),
+            };
+            AppError::invalid_field(
+                field,
+                format!(
+                    "'{value}' is not a priority; use none, low, medium or high \
+                     (also !, !!, !!!, urgent, alta, media, baja, or EventKit 0-9)"
+                ),
+            )
+        })
+    }
+
+    fn from_eventkit(number: i64) -> Option<Self> {
+        match number {
+            0 => Some(Self::None),
+            1..=4 => Some(Self::High),
+            5 => Some(Self::Medium),
+            6..=9 => Some(Self::Low),
+            _ => None,
+        }
+    }
+
+    fn from_text(text: &str) -> Option<Self> {
+        let text = text.trim().to_lowercase();
+        if let Ok(number) = text.parse::<i64>() {
+            return Self::from_eventkit(number);
+        }
+        match text.as_str() {
+            "" | "none" | "no" | "ninguna" | "sin" | "sin prioridad" => Some(Self::None),
+            "low" | "!" | "baja" | "bajo" => Some(Self::Low),
+            "medium" | "med" | "normal" | "!!" | "media" | "medio" => Some(Self::Medium),
+            "high" | "!!!" | "urgent" | "important" | "alta" | "alto" | "urgente"
+            | "importante" => Some(Self::High),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Reads `priority` from remindctl output, falling back to none (and logging) for values
+/// it cannot map, so one odd reminder does not fail the whole read.
+pub fn deserialize_lenient<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Priority, D::Error> {
+    let value = Option::<Value>::deserialize(deserializer)?;
+    let Some(value) = value.filter(|value| !value.is_null()) else {
+        return Ok(Priority::None);
+    };
+    let priority = serde_json::from_value::<PriorityInput>(value.clone())
+        .ok()
+        .and_then(|input| Priority::parse(&input, "priority").ok());
+    Ok(priority.unwrap_or_else(|| {
+        tracing::warn!(priority = %value, "ignoring unreadable priority");
+        Priority::None
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_common_spellings_and_eventkit_numbers() -> Result<(), AppError> {
+        let cases = [
+            (PriorityInput::Text("!!!".to_owned()), Priority::High),
+            (PriorityInput::Text("Urgente".to_owned()), Priority::High),
+            (PriorityInput::Text("media".to_owned()), Priority::Medium),
+            (PriorityInput::Text("!".to_owned()), Priority::Low),
+            (PriorityInput::Number(1), Priority::High),
+            (PriorityInput::Number(5), Priority::Medium),
+            (PriorityInput::Text("9".to_owned()), Priority::Low),
+            (PriorityInput::Number(0), Priority::None),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(Priority::parse(&input, "priority")?, expected, "{input:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unreadable_remindctl_priorities_fall_back_to_none() -> Result<(), serde_json::Error> {
+        for (raw, expected) in [
+            (serde_json::json!(null), Priority::None),
+            (serde_json::json!("someday"), Priority::None),
+            (serde_json::json!([1]), Priority::None),
+            (serde_json::json!(1), Priority::High),
+        ] {
+            let reminder: crate::models::Reminder = serde_json::from_value(serde_json::json!({
+                "id": "A", "title": "t", "listID": "L", "listName": "l",
+                "isCompleted": false, "dueDate": null, "priority": raw
+            }))?;
+            assert_eq!(reminder.priority, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_unknown_values_naming_the_field() {
+        for input in [
+            PriorityInput::Text("asap-ish".to_owned()),
+            PriorityInput::Number(12),
+        ] {
+            let err = Priority::parse(&input, "minPriority").err();
+            assert_eq!(err.as_ref().and_then(AppError::field), Some("minPriority"));
+        }
+    }
+}
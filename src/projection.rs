@@ -0,0 +1,275 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::alarms::Alarm;
+use crate::error::AppError;
+use crate::recurrence::Recurrence;
+
+/// Notes longer than this many characters are cut in compact output and tables.
+const NOTES_PREVIEW_CHARS: usize = 80;
+
+/// Reminder fields `fields` can name, with their `compact` keys.
+pub const FIELDS: [(&str, &str); 17] = [
+    ("id", "i"),
+    ("title", "t"),
+    ("listID", "li"),
+    ("listName", "l"),
+    ("isCompleted", "c"),
+    ("priority", "p"),
+    ("dueDate", "dd"),
+    ("due", "d"),
+    ("notes", "n"),
+    ("url", "u"),
+    ("flagged", "f"),
+    ("recurrence", "r"),
+    ("alarms", "a"),
+    ("startDate", "s"),
+    ("creationDate", "cr"),
+    ("lastModifiedDate", "m"),
+    ("completionDate", "cd"),
+];
+
+/// Table columns when `fields` is not given.
+const TABLE_FIELDS: [&str; 6] = ["id", "title", "listName", "due", "priority", "isCompleted"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Reminders as the output schema describes them.
+    #[default]
+    Json,
+    /// Short keys, defaults dropped, notes trimmed, due as one local time.
+    Compact,
+    /// `json` structured content, with a tab-separated table as the text content.
+    Table,
+}
+
+/// Which reminder fields a read returns and how. `id` is always kept so results can
+/// be acted on.
+#[derive(Debug, Clone, Default)]
+pub struct Projection {
+    fields: Option<Vec<&'static str>>,
+    format: OutputFormat,
+}
+
+impl Projection {
+    /// Reads `fields` (names from [`FIELDS`], any case, comma-separated or not) and
+    /// `format`. Unknown names fail with `invalid_input` on `fields`.
+    pub fn parse(
+        fields: Option<&[String]>,
+        format: Option<OutputFormat>,
+    ) -> Result<Self, AppError> {
+        let names = fields
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|entry| entry.split(','))
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .collect::<Vec<_>>();
+        let fields = if names.is_empty() {
+            None
+        } else {
+            let mut selected = vec!["id"];
+            for name in names {
+                let Some((field, _)) = FIELDS
+                    .iter()
+                    .find(|(field, _)| field.eq_ignore_ascii_case(name))
+                else {
+                    let known = FIELDS.map(|(field, _)| field).join(", ");
+                    return Err(AppError::invalid_field(
+                        "fields",
+                        format!("unknown field '{name}'; use {known}"),
+                    ));
+                };
+                if !selected.contains(field) {
+                    selected.push(field);
+                }
+            }
+            Some(selected)
+        };
+        Ok(Self {
+            fields,
+            format: format.unwrap_or_default(),
+        })
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Shapes one serialized reminder in place.
+    pub fn shape(&self, reminder: &mut Value) {
+        let Value::Object(full) = reminder.take() else {
+            return;
+        };
+        let mut shaped = Map::new();
+        for (key, value) in &full {
+            if self
+                .fields
+                .as_ref()
+                .is_some_and(|fields| !fields.contains(&key.as_str()))
+            {
+                continue;
+            }
+            if self.format != OutputFormat::Compact {
+                shaped.insert(key.clone(), value.clone());
+            } else if key == "dueDate" && full.contains_key("due") {
+                // `due` already carries the same moment, localised.
+            } else if let Some(value) = compact_value(key, value) {
+                shaped.insert(short_key(key).to_owned(), value);
+            }
+        }
+        *reminder = Value::Object(shaped);
+    }
+
+    /// Shapes every reminder in a serialized array in place.
+    pub fn shape_all(&self, reminders: &mut Value) {
+        if let Value::Array(items) = reminders {
+            items.iter_mut().for_each(|item| self.shape(item));
+        }
+    }
+
+    /// The `table` text for serialized reminders: a header row of field names, then
+    /// one tab-separated row each. `None` for other formats.
+    pub fn table<'a>(&self, reminders: impl IntoIterator<Item = &'a Value>) -> Option<String> {
+        if self.format != OutputFormat::Table {
+            return None;
+        }
+        let columns = self.fields.as_deref().unwrap_or(&TABLE_FIELDS);
+        let mut lines = vec![columns.join("\t")];
+        for reminder in reminders {
+            let cells = columns
+                .iter()
+                .map(|column| {
+                    reminder
+                        .get(column)
+                        .and_then(|value| compact_value(column, value))
+                        .map(|value| cell(&value))
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+            lines.push(cells.join("\t"));
+        }
+        Some(lines.join("\n"))
+    }
+}
+
+fn short_key(key: &str) -> &str {
+    FIELDS
+        .iter()
+        .find(|(field, _)| *field == key)
+        .map_or(key, |(_, short)| short)
+}
+
+/// A field's compact form, or `None` when it holds nothing worth sending: null, false,
+/// empty, or priority none.
+fn compact_value(key: &str, value: &Value) -> Option<Value> {
+    let compact = match (key, value) {
+        (_, Value::Null | Value::Bool(false)) => return None,
+        (_, Value::String(text)) if text.is_empty() => return None,
+        (_, Value::Array(items)) if items.is_empty() => return None,
+        ("priority", Value::String(priority)) if priority == "none" => return None,
+        ("due", due) => {
+            let local = match due.get("allDay") {
+                Some(Value::Bool(true)) => due.get("date"),
+                _ => due.get("local"),
+            };
+            local.cloned()?
+        }
+        ("notes", Value::String(notes)) => Value::String(preview(notes)),
+        ("recurrence", recurrence) => serde_json::from_value::<Recurrence>(recurrence.clone())
+            .map(|recurrence| Value::String(recurrence.to_rrule()))
+            .unwrap_or_else(|_| recurrence.clone()),
+        ("alarms", alarms) => serde_json::from_value::<Vec<Alarm>>(alarms.clone())
+            .map(|alarms| alarms.iter().map(Alarm::remindctl_arg).collect())
+            .unwrap_or_else(|_| alarms.clone()),
+        (_, value) => value.clone(),
+    };
+    Some(compact)
+}
+
+/// Notes on one line, cut to [`NOTES_PREVIEW_CHARS`] with an ellipsis.
+fn preview(notes: &str) -> String {
+    let line = notes.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= NOTES_PREVIEW_CHARS {
+        return line;
+    }
+    let cut = line.chars().take(NOTES_PREVIEW_CHARS).collect::<String>();
+    format!("{}…", cut.trim_end())
+}
+
+fn cell(value: &Value) -> String {
+    let text = match value {
+        Value::String(text) => text.clone(),
+        Value::Bool(true) => "yes".to_owned(),
+        Value::Array(items) => items.iter().map(cell).collect::<Vec<_>>().join(","),
+        other => other.to_string(),
+    };
+    text.replace(['\t', '\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn reminder() -> Value {
+        json!({
+            "id": "AAAA-1111",
+            "title": "Pay rent",
+            "listID": "L-1",
+            "listName": "Home",
+            "isCompleted": false,
+            "priority": "none",
+            "dueDate": "2026-03-01T09:00:00Z",
+            "due": { "local": "2026-03-01T10:00:00+01:00", "date": "2026-03-01", "allDay": false },
+            "notes": format!("Transfer\n{}", "x".repeat(100)),
+            "recurrence": { "frequency": "monthly", "interval": 1, "byMonthDay": [1] },
+            "alarms": [{ "type": "relative", "minutesBefore": 60 }],
+            "flagged": null
+        })
+    }
+
+    #[test]
+    fn compact_uses_short_keys_and_drops_defaults() -> Result<(), AppError> {
+        let mut value = reminder();
+        Projection::parse(None, Some(OutputFormat::Compact))?.shape(&mut value);
+        assert_eq!(value["d"], json!("2026-03-01T10:00:00+01:00"));
+        assert_eq!(value["r"], json!("FREQ=MONTHLY;BYMONTHDAY=1"));
+        assert_eq!(value["a"], json!(["-60m"]));
+        let notes = value["n"].as_str().unwrap_or_default();
+        assert!(
+            notes.starts_with("Transfer xx") && notes.ends_with('…'),
+            "{notes}"
+        );
+        for dropped in ["c", "p", "dd", "f", "isCompleted"] {
+            assert_eq!(value.get(dropped), None, "{dropped}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fields_keep_id_and_render_tables() -> Result<(), AppError> {
+        let fields = vec!["TITLE, due".to_owned()];
+        let projection = Projection::parse(Some(&fields), Some(OutputFormat::Table))?;
+        let rows = [reminder()];
+        assert_eq!(
+            projection.table(&rows).as_deref(),
+            Some("id\ttitle\tdue\nAAAA-1111\tPay rent\t2026-03-01T10:00:00+01:00")
+        );
+        let mut value = reminder();
+        projection.shape(&mut value);
+        let mut keys = value
+            .as_object()
+            .map(|map| map.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        keys.sort();
+        assert_eq!(keys, vec!["due", "id", "title"]);
+
+        let err = Projection::parse(Some(&["colour".to_owned()]), None).err();
+        assert_eq!(err.as_ref().and_then(AppError::field), Some("fields"));
+        Ok(())
+    }
+}
@@ -0,0 +1,609 @@
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::dates::{Due, ServerTimezone};
+use crate::error::AppError;
+use crate::models::{Reminder, ReminderList};
+use crate::priority::{Priority, PriorityInput};
+use crate::text::fold;
+
+const FIELDS: &str = "list, text, has, is, priority, due, completed, flagged, overdue, recurring";
+const HAS_FIELDS: &str = "notes, due, url, recurrence, alarms, priority";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryField {
+    List,
+    Text,
+    Has,
+    Priority,
+    Due,
+    Completed,
+    Flagged,
+    Overdue,
+    Recurring,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum CompareOp {
+    #[serde(rename = "eq", alias = "=", alias = ":")]
+    Eq,
+    #[serde(rename = "ne", alias = "!=")]
+    Ne,
+    #[serde(rename = "lt", alias = "<")]
+    Lt,
+    #[serde(rename = "lte", alias = "<=")]
+    Lte,
+    #[serde(rename = "gt", alias = ">")]
+    Gt,
+    #[serde(rename = "gte", alias = ">=")]
+    Gte,
+}
+
+impl CompareOp {
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Lt => ordering.is_lt(),
+            Self::Lte => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Gte => ordering.is_ge(),
+        }
+    }
+}
+
+/// One condition of the structured query form. Conditions are ANDed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct QueryCondition {
+    pub field: QueryField,
+    /// Defaults to `eq`. `lt`, `lte`, `gt` and `gte` apply to `priority` and `due`.
+    #[serde(default)]
+    pub op: Option<CompareOp>,
+    /// A list name or ID, text, a `has` field (notes, due, url, recurrence, alarms,
+    /// priority), a priority, a due date or phrase, or true/false for flags.
+    #[serde(default)]
+    pub value: Option<Value>,
+    /// Inverts the condition.
+    #[serde(default)]
+    pub not: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HasField {
+    Notes,
+    Due,
+    Url,
+    Recurrence,
+    Alarms,
+    Priority,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flag {
+    Completed,
+    Flagged,
+    Overdue,
+    Recurring,
+}
+
+#[derive(Debug, Clone)]
+enum Predicate {
+    List(String),
+    Text(String),
+    Has(HasField),
+    Flag(Flag),
+    Priority(CompareOp, Priority),
+    Due(CompareOp, Due),
+}
+
+#[derive(Debug, Clone)]
+struct Clause {
+    negated: bool,
+    predicate: Predicate,
+}
+
+/// A value as written: DSL text, or JSON from the structured form.
+enum Operand {
+    Missing,
+    Text(String),
+    Bool(bool),
+    Number(i64),
+}
+
+/// What clauses are resolved against: list names become IDs, dates resolve in `tz`.
+pub struct QueryContext<'a> {
+    pub lists: &'a [ReminderList],
+    pub tz: ServerTimezone,
+    pub now: DateTime<Utc>,
+}
+
+/// A validated reminder query: every clause must hold.
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    clauses: Vec<Clause>,
+}
+
+impl Query {
+    /// Parses the text form, e.g. `list:Compras priority>=medium due<2026-11-01 !completed
+    /// text:"leche" has:notes`. Errors name `query` and quote the offending token.
+    pub fn parse(text: &str, context: &QueryContext<'_>) -> Result<Self, AppError> {
+        let chars = text.chars().collect::<Vec<_>>();
+        let mut clauses = Vec::new();
+        for token in tokenize(&chars)? {
+            let raw = chars[token.start..token.end].iter().collect::<String>();
+            let clause = parse_term(&token.chars, context).map_err(|problem| {
+                AppError::invalid_field(
+                    "query",
+                    format!("{problem} in `{raw}` at column {}", token.start + 1),
+                )
+            })?;
+            clauses.push(clause);
+        }
+        Ok(Self { clauses })
+    }
+
+    /// Builds the structured form; errors name the condition as `where[i]`.
+    pub fn from_conditions(
+        conditions: &[QueryCondition],
+        context: &QueryContext<'_>,
+    ) -> Result<Self, AppError> {
+        let clauses = conditions
+            .iter()
+            .enumerate()
+            .map(|(index, condition)| {
+                let operand = match &condition.value {
+                    None | Some(Value::Null) => Operand::Missing,
+                    Some(Value::String(text)) => Operand::Text(text.clone()),
+                    Some(Value::Bool(flag)) => Operand::Bool(*flag),
+                    Some(Value::Number(number)) => match number.as_i64() {
+                        Some(number) => Operand::Number(number),
+                        None => Operand::Text(number.to_string()),
+                    },
+                    Some(other) => Operand::Text(other.to_string()),
+                };
+                let op = condition.op.unwrap_or(CompareOp::Eq);
+                build(condition.field, op, operand, context)
+                    .map(|clause| Clause {
+                        negated: clause.negated != condition.not,
+                        ..clause
+                    })
+                    .map_err(|problem| AppError::invalid_field(format!("where[{index}]"), problem))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { clauses })
+    }
+
+    pub fn and(mut self, other: Self) -> Self {
+        self.clauses.extend(other.clauses);
+        self
+    }
+
+    /// Whether any clause looks at completion, in which case completed reminders are
+    /// not hidden by default.
+    pub fn mentions_completion(&self) -> bool {
+        self.clauses
+            .iter()
+            .any(|clause| matches!(clause.predicate, Predicate::Flag(Flag::Completed)))
+    }
+
+    pub fn matches(&self, reminder: &Reminder, tz: ServerTimezone, now: DateTime<Utc>) -> bool {
+        self.clauses
+            .iter()
+            .all(|clause| clause.predicate.holds(reminder, tz, now) != clause.negated)
+    }
+}
+
+impl Predicate {
+    fn holds(&self, reminder: &Reminder, tz: ServerTimezone, now: DateTime<Utc>) -> bool {
+        let due = || {
+            reminder
+                .due_date
+                .as_deref()
+                .and_then(|due| Due::parse(due, tz).ok())
+        };
+        match self {
+            Self::List(id) => reminder.list_id.eq_ignore_ascii_case(id),
+            Self::Text(text) => {
+                fold(&reminder.title).contains(text.as_str())
+                    || fold(&reminder.notes).contains(text.as_str())
+            }
+            Self::Has(field) => match field {
+                HasField::Notes => !reminder.notes.trim().is_empty(),
+                HasField::Due => reminder.due_date.is_some(),
+                HasField::Url => reminder.url.as_deref().is_some_and(|url| !url.is_empty()),
+                HasField::Recurrence => reminder.recurrence.is_some(),
+                HasField::Alarms => reminder
+                    .alarms
+                    .as_ref()
+                    .is_some_and(|alarms| !alarms.is_empty()),
+                HasField::Priority => reminder.priority != Priority::None,
+            },
+            Self::Flag(flag) => match flag {
+                Flag::Completed => reminder.is_completed,
+                Flag::Flagged => reminder.flagged == Some(true),
+                Flag::Overdue => {
+                    !reminder.is_completed
+                        && due()
+                            .and_then(|due| due.instant(tz))
+                            .is_some_and(|instant| instant < now)
+                }
+                Flag::Recurring => reminder.recurrence.is_some(),
+            },
+            Self::Priority(op, priority) => op.holds(reminder.priority.cmp(priority)),
+            Self::Due(op, bound) => due().is_some_and(|due| {
+                let ordering = match bound {
+                    Due::AllDay(date) => due.local_date(tz).cmp(date),
+                    Due::At(instant) => match due.instant(tz) {
+                        Some(due) => due.cmp(instant),
+                        None => return false,
+                    },
+                };
+                op.holds(ordering)
+            }),
+        }
+    }
+}
+
+/// One whitespace-separated term. Quoted characters never act as operators.
+struct Token {
+    start: usize,
+    end: usize,
+    chars: Vec<(char, bool)>,
+}
+
+fn tokenize(chars: &[char]) -> Result<Vec<Token>, AppError> {
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        if chars[index].is_whitespace() {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        let mut token = Vec::new();
+        while index < chars.len() && !chars[index].is_whitespace() {
+            if chars[index] == '"' {
+                let Some(close) = chars[index + 1..].iter().position(|ch| *ch == '"') else {
+                    return Err(AppError::invalid_field(
+                        "query",
+                        format!("unterminated quote at column {}", index + 1),
+                    ));
+                };
+                token.extend(
+                    chars[index + 1..index + 1 + close]
+                        .iter()
+                        .map(|ch| (*ch, true)),
+                );
+                index += close + 2;
+            } else {
+                token.push((chars[index], false));
+                index += 1;
+            }
+        }
+        tokens.push(Token {
+            start,
+            end: index,
+            chars: token,
+        });
+    }
+    Ok(tokens)
+}
+
+fn parse_term(chars: &[(char, bool)], context: &QueryContext<'_>) -> Result<Clause, String> {
+    let (negated, chars) = match chars {
+        [('!' | '-', false), rest @ ..] if !rest.is_empty() => (true, rest),
+        _ => (false, chars),
+    };
+    let operator = chars.iter().enumerate().find_map(|(index, (ch, quoted))| {
+        let next = chars.get(index + 1).map(|(next, _)| *next);
+        let op = match (ch, quoted, next) {
+            (_, true, _) => return None,
+            (':', _, _) => (CompareOp::Eq, 1),
+            ('>', _, Some('=')) => (CompareOp::Gte, 2),
+            ('<', _, Some('=')) => (CompareOp::Lte, 2),
+            ('!', _, Some('=')) => (CompareOp::Ne, 2),
+            ('>', _, _) => (CompareOp::Gt, 1),
+            ('<', _, _) => (CompareOp::Lt, 1),
+            ('=', _, _) => (CompareOp::Eq, 1),
+            _ => return None,
+        };
+        Some((index, op))
+    });
+    let text = |chars: &[(char, bool)]| chars.iter().map(|(ch, _)| *ch).collect::<String>();
+
+    let clause = match operator {
+        Some((index, (op, width))) => {
+            let key = fold(&text(&chars[..index]));
+            let value = text(&chars[index + width..]);
+            if key.is_empty() {
+                return Err("missing field name before the operator".to_owned());
+            }
+            if value.is_empty() {
+                return Err(format!("missing value after `{key}`"));
+            }
+            let field = match key.as_str() {
+                "is" => flag_field(&fold(&value)).ok_or_else(|| {
+                    format!("unknown flag '{value}'; use completed, flagged, overdue or recurring")
+                })?,
+                key => field_named(key)
+                    .ok_or_else(|| format!("unknown field '{key}'; use {FIELDS}"))?,
+            };
+            let operand = if key == "is" {
+                Operand::Missing
+            } else {
+                Operand::Text(value)
+            };
+            build(field, op, operand, context)?
+        }
+        None => {
+            let word = text(chars);
+            let unquoted = chars.iter().all(|(_, quoted)| !quoted);
+            match flag_field(&fold(&word)).filter(|_| unquoted) {
+                Some(field) => build(field, CompareOp::Eq, Operand::Missing, context)?,
+                None => build(
+                    QueryField::Text,
+                    CompareOp::Eq,
+                    Operand::Text(word),
+                    context,
+                )?,
+            }
+        }
+    };
+    Ok(Clause {
+        negated: clause.negated != negated,
+        ..clause
+    })
+}
+
+fn field_named(name: &str) -> Option<QueryField> {
+    Some(match name {
+        "list" => QueryField::List,
+        "text" => QueryField::Text,
+        "has" => QueryField::Has,
+        "priority" => QueryField::Priority,
+        "due" => QueryField::Due,
+        other => return flag_field(other),
+    })
+}
+
+fn flag_field(name: &str) -> Option<QueryField> {
+    Some(match name {
+        "completed" => QueryField::Completed,
+        "flagged" => QueryField::Flagged,
+        "overdue" => QueryField::Overdue,
+        "recurring" => QueryField::Recurring,
+        _ => return None,
+    })
+}
+
+/// Validates one condition; shared by the text and structured forms.
+fn build(
+    field: QueryField,
+    op: CompareOp,
+    operand: Operand,
+    context: &QueryContext<'_>,
+) -> Result<Clause, String> {
+    let ordered = matches!(field, QueryField::Priority | QueryField::Due);
+    if !ordered && !matches!(op, CompareOp::Eq | CompareOp::Ne) {
+        return Err("<, <=, > and >= only apply to priority and due".to_owned());
+    }
+    let mut negated = false;
+    let text = |operand: Operand| match operand {
+        Operand::Text(text) if !text.trim().is_empty() => Ok(text),
+        Operand::Number(number) => Ok(number.to_string()),
+        _ => Err("a text value is required".to_owned()),
+    };
+
+    let predicate = match field {
+        QueryField::List => {
+            let value = text(operand)?;
+            let folded = fold(&value);
+            let list = context
+                .lists
+                .iter()
+                .find(|list| list.id.eq_ignore_ascii_case(&value) || fold(&list.title) == folded)
+                .ok_or_else(|| format!("unknown list '{value}'"))?;
+            negated = op == CompareOp::Ne;
+            Predicate::List(list.id.clone())
+        }
+        QueryField::Text => {
+            negated = op == CompareOp::Ne;
+            Predicate::Text(fold(&text(operand)?))
+        }
+        QueryField::Has => {
+            let value = text(operand)?;
+            let has = match fold(&value).as_str() {
+                "notes" => HasField::Notes,
+                "due" => HasField::Due,
+                "url" => HasField::Url,
+                "recurrence" => HasField::Recurrence,
+                "alarms" | "alarm" => HasField::Alarms,
+                "priority" => HasField::Priority,
+                _ => return Err(format!("unknown has: value '{value}'; use {HAS_FIELDS}")),
+            };
+            negated = op == CompareOp::Ne;
+            Predicate::Has(has)
+        }
+        QueryField::Priority => {
+            let input = match operand {
+                Operand::Text(text) => PriorityInput::Text(text),
+                Operand::Number(number) => PriorityInput::Number(number),
+                _ => return Err("a priority is required".to_owned()),
+            };
+            let priority = Priority::parse(&input, "query")
+                .map_err(|_| "not a priority; use none, low, medium or high".to_owned())?;
+            Predicate::Priority(op, priority)
+        }
+        QueryField::Due => {
+            let value = text(operand)?;
+            let due = Due::resolve(&value, context.tz, context.now).map_err(|_| {
+                format!(
+                    "'{value}' is not a date; use YYYY-MM-DD, RFC3339 or a phrase like tomorrow"
+                )
+            })?;
+            Predicate::Due(op, due)
+        }
+        QueryField::Completed
+        | QueryField::Flagged
+        | QueryField::Overdue
+        | QueryField::Recurring => {
+            let wanted = match operand {
+                Operand::Missing => true,
+                Operand::Bool(wanted) => wanted,
+                Operand::Text(text) => match fold(&text).as_str() {
+                    "true" | "yes" | "si" => true,
+                    "false" | "no" => false,
+                    _ => return Err(format!("'{text}' is not true or false")),
+                },
+                Operand::Number(_) => return Err("expected true or false".to_owned()),
+            };
+            negated = wanted == (op == CompareOp::Ne);
+            let flag = match field {
+                QueryField::Completed => Flag::Completed,
+                QueryField::Flagged => Flag::Flagged,
+                QueryField::Overdue => Flag::Overdue,
+                _ => Flag::Recurring,
+            };
+            Predicate::Flag(flag)
+        }
+    };
+    Ok(Clause { negated, predicate })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn lists() -> Vec<ReminderList> {
+        ["Compras", "Work"]
+            .iter()
+            .enumerate()
+            .map(|(index, title)| ReminderList {
+                id: format!("L-{index}"),
+                title: (*title).to_owned(),
+                reminder_count: None,
+                overdue_count: None,
+            })
+            .collect()
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 9, 0, 0)
+            .single()
+            .unwrap_or_default()
+    }
+
+    fn reminder(title: &str, list_id: &str, priority: Priority, due: Option<&str>) -> Reminder {
+        Reminder {
+            id: title.to_owned(),
+            title: title.to_owned(),
+            list_id: list_id.to_owned(),
+            priority,
+            due_date: due.map(str::to_owned),
+            ..Reminder::default()
+        }
+    }
+
+    fn parse(text: &str) -> Result<Query, AppError> {
+        let lists = lists();
+        let context = QueryContext {
+            lists: &lists,
+            tz: ServerTimezone::Named(chrono_tz::UTC),
+            now: now(),
+        };
+        Query::parse(text, &context)
+    }
+
+    #[test]
+    fn evaluates_the_documented_example() -> Result<(), AppError> {
+        let query = parse(
+            r#"list:compras priority>=medium due<2026-11-01 !completed text:"LECHE" has:notes"#,
+        )?;
+        let tz = ServerTimezone::Named(chrono_tz::UTC);
+        let mut hit = reminder("Comprar leche", "L-0", Priority::High, Some("2026-10-20"));
+        hit.notes = "sin lactosa".to_owned();
+        assert!(query.matches(&hit, tz, now()));
+
+        let mut done = hit.clone();
+        done.is_completed = true;
+        let late = Reminder {
+            due_date: Some("2026-11-01".to_owned()),
+            ..hit.clone()
+        };
+        let low = Reminder {
+            priority: Priority::Low,
+            ..hit.clone()
+        };
+        let elsewhere = Reminder {
+            list_id: "L-1".to_owned(),
+            ..hit
+        };
+        for miss in [done, late, low, elsewhere] {
+            assert!(!query.matches(&miss, tz, now()), "{}", miss.title);
+        }
+        assert!(query.mentions_completion());
+        Ok(())
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_token() {
+        let cases = [
+            ("list:Compras prio>=high", "`prio>=high` at column 14"),
+            ("due<someday", "`due<someday` at column 1"),
+            ("list:Nope", "unknown list 'Nope'"),
+            ("has:photos", "unknown has: value"),
+            ("text>leche", "only apply to priority and due"),
+            ("text:\"leche", "unterminated quote at column 6"),
+        ];
+        for (text, expected) in cases {
+            let message = parse(text)
+                .err()
+                .map(|err| err.to_string())
+                .unwrap_or_default();
+            assert!(message.contains(expected), "{text}: {message}");
+        }
+    }
+
+    #[test]
+    fn structured_conditions_match_the_text_form() -> Result<(), AppError> {
+        let lists = lists();
+        let context = QueryContext {
+            lists: &lists,
+            tz: ServerTimezone::Named(chrono_tz::UTC),
+            now: now(),
+        };
+        let conditions: Vec<QueryCondition> = serde_json::from_value(serde_json::json!([
+            { "field": "priority", "op": ">=", "value": "medium" },
+            { "field": "overdue" },
+            { "field": "list", "value": "Work", "not": true }
+        ]))
+        .map_err(|err| AppError::invalid_input(err.to_string()))?;
+        let query = Query::from_conditions(&conditions, &context)?;
+        let tz = ServerTimezone::Named(chrono_tz::UTC);
+        assert!(query.matches(
+            &reminder("a", "L-0", Priority::High, Some("2026-10-15")),
+            tz,
+            now()
+        ));
+        assert!(!query.matches(
+            &reminder("b", "L-1", Priority::High, Some("2026-10-15")),
+            tz,
+            now()
+        ));
+
+        let bad = serde_json::from_value::<Vec<QueryCondition>>(serde_json::json!([
+            { "field": "due", "op": "lt", "value": "whenever" }
+        ]))
+        .map_err(|err| AppError::invalid_input(err.to_string()))?;
+        let err = Query::from_conditions(&bad, &context).err();
+        assert_eq!(err.as_ref().and_then(AppError::field), Some("where[0]"));
+        Ok(())
+    }
+}
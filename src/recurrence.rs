@@ -0,0 +1,635 @@
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, Utc, Weekday};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::dates::{Due, ServerTimezone};
+use crate::error::AppError;
+
+/// Upper bound on periods (days, weeks, months, years) scanned per expansion, so rules
+/// that rarely match (e.g. the 31st every 2 months) cannot loop forever.
+const MAX_PERIODS: u32 = 10_000;
+
+const WEEKDAY_CODES: [(&str, Weekday); 7] = [
+    ("MO", Weekday::Mon),
+    ("TU", Weekday::Tue),
+    ("WE", Weekday::Wed),
+    ("TH", Weekday::Thu),
+    ("FR", Weekday::Fri),
+    ("SA", Weekday::Sat),
+    ("SU", Weekday::Sun),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    fn as_rrule(self) -> &'static str {
+        match self {
+            Self::Daily => "DAILY",
+            Self::Weekly => "WEEKLY",
+            Self::Monthly => "MONTHLY",
+            Self::Yearly => "YEARLY",
+        }
+    }
+}
+
+/// A `BYDAY` entry: a weekday, optionally the nth of the month (`1MO`, `-1FR`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ByDay {
+    pub ordinal: Option<i8>,
+    pub weekday: Weekday,
+}
+
+impl TryFrom<String> for ByDay {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let upper = value.trim().to_ascii_uppercase();
+        let not_a_weekday =
+            || format!("'{value}' is not a weekday; use MO, TU, WE, TH, FR, SA, SU");
+        let (ordinal, code) = upper
+            .split_at_checked(upper.len().saturating_sub(2))
+            .ok_or_else(not_a_weekday)?;
+        let weekday = WEEKDAY_CODES
+            .iter()
+            .find(|(name, _)| *name == code)
+            .map(|(_, weekday)| *weekday)
+            .ok_or_else(not_a_weekday)?;
+        let ordinal = match ordinal {
+            "" => None,
+            ordinal => Some(
+                ordinal
+                    .trim_start_matches('+')
+                    .parse::<i8>()
+                    .ok()
+                    .filter(|ordinal| *ordinal != 0 && (-5..=5).contains(ordinal))
+                    .ok_or_else(|| {
+                        format!("'{value}' has an invalid ordinal; use 1-5 or -1 to -5")
+                    })?,
+            ),
+        };
+        Ok(Self { ordinal, weekday })
+    }
+}
+
+impl From<ByDay> for String {
+    fn from(value: ByDay) -> Self {
+        value.to_string()
+    }
+}
+
+impl fmt::Display for ByDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = WEEKDAY_CODES
+            .iter()
+            .find(|(_, weekday)| *weekday == self.weekday)
+            .map_or("MO", |(name, _)| name);
+        match self.ordinal {
+            Some(ordinal) => write!(f, "{ordinal}{code}"),
+            None => f.write_str(code),
+        }
+    }
+}
+
+/// A recurrence rule, a subset of RFC 5545 `RRULE` that Reminders supports.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    /// Every `interval` days, weeks, months or years.
+    #[serde(default = "default_interval")]
+    pub interval: u32,
+    /// Weekdays (`MO`..`SU`) for weekly rules; monthly rules also take an ordinal (`1MO`, `-1FR`).
+    #[serde(rename = "byDay", default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(with = "Vec<String>")]
+    pub by_day: Vec<ByDay>,
+    /// Days of the month for monthly rules; negative counts from the end (`-1` is the last day).
+    #[serde(rename = "byMonthDay", default, skip_serializing_if = "Vec::is_empty")]
+    pub by_month_day: Vec<i32>,
+    /// Last possible occurrence, `YYYY-MM-DD` or RFC3339.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    /// Total number of occurrences, counting from the start.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+}
+
+fn default_interval() -> u32 {
+    1
+}
+
+/// What `recurrence` accepts on input: an `RRULE` string or the structured form.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum RecurrenceInput {
+    Rule(String),
+    Structured(Recurrence),
+}
+
+impl Recurrence {
+    /// Parses tool input, naming `recurrence` in every error. A floating `until` is
+    /// read in `tz`, like `due`.
+    pub fn from_input(value: &Value, tz: ServerTimezone) -> Result<Self, AppError> {
+        Self::read(value)?.resolve_until(tz)
+    }
+
+    /// `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE`, with or without an `RRULE:` prefix. A
+    /// floating `UNTIL` is read in `tz`.
+    pub fn parse_rrule(rule: &str, tz: ServerTimezone) -> Result<Self, AppError> {
+        Self::parse_rule(rule)?.resolve_until(tz)
+    }
+
+    /// Like [`Self::from_input`] but leaves `until` as written, for remindctl output
+    /// that is resolved later by [`Self::localized`].
+    fn read(value: &Value) -> Result<Self, AppError> {
+        let rule = match value {
+            Value::String(rule) => Self::parse_rule(rule)?,
+            Value::Object(_) => serde_json::from_value::<Self>(value.clone())
+                .map_err(|err| AppError::invalid_field("recurrence", err.to_string()))?,
+            _ => {
+                return Err(AppError::invalid_field(
+                    "recurrence",
+                    "expected an RRULE string or {frequency, interval, byDay, byMonthDay, until, count}",
+                ));
+            }
+        };
+        rule.validated()
+    }
+
+    fn parse_rule(rule: &str) -> Result<Self, AppError> {
+        let invalid = |message: String| AppError::invalid_field("recurrence", message);
+        let body = rule.trim();
+        let body = body
+            .strip_prefix("RRULE:")
+            .or_else(|| body.strip_prefix("rrule:"))
+            .unwrap_or(body);
+
+        let mut frequency = None;
+        let mut recurrence = Self {
+            frequency: Frequency::Daily,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            until: None,
+            count: None,
+        };
+        for part in body.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("'{part}' is not KEY=VALUE")))?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        other => {
+                            return Err(invalid(format!(
+                                "FREQ={other} is not supported; use DAILY, WEEKLY, MONTHLY or YEARLY"
+                            )));
+                        }
+                    });
+                }
+                "INTERVAL" => {
+                    recurrence.interval = value
+                        .parse()
+                        .map_err(|_| invalid(format!("INTERVAL={value} is not a number")))?;
+                }
+                "BYDAY" => {
+                    recurrence.by_day = value
+                        .split(',')
+                        .map(|day| ByDay::try_from(day.to_owned()).map_err(invalid))
+                        .collect::<Result<_, _>>()?;
+                }
+                "BYMONTHDAY" => {
+                    recurrence.by_month_day = value
+                        .split(',')
+                        .map(|day| {
+                            day.parse().map_err(|_| {
+                                invalid(format!("BYMONTHDAY={value} is not a number list"))
+                            })
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                "UNTIL" => {
+                    recurrence.until = Some(rrule_until(value).ok_or_else(|| {
+                        invalid(format!("UNTIL={value} is not YYYYMMDD or YYYYMMDDTHHMMSSZ"))
+                    })?)
+                }
+                "COUNT" => {
+                    recurrence.count = Some(
+                        value
+                            .parse()
+                            .map_err(|_| invalid(format!("COUNT={value} is not a number")))?,
+                    );
+                }
+                other => {
+                    return Err(invalid(format!(
+                        "{other} is not supported in recurrence rules"
+                    )));
+                }
+            }
+        }
+        recurrence.frequency = frequency.ok_or_else(|| invalid("FREQ is required".to_owned()))?;
+        recurrence.validated()
+    }
+
+    fn validated(self) -> Result<Self, AppError> {
+        let invalid = |message: &str| Err(AppError::invalid_field("recurrence", message));
+        if self.interval == 0 {
+            return invalid("interval must be at least 1");
+        }
+        if self.count == Some(0) {
+            return invalid("count must be at least 1");
+        }
+        if self.count.is_some() && self.until.is_some() {
+            return invalid("use either count or until, not both");
+        }
+        if self
+            .by_month_day
+            .iter()
+            .any(|day| *day == 0 || !(-31..=31).contains(day))
+        {
+            return invalid("byMonthDay values must be 1 to 31 or -1 to -31");
+        }
+        match self.frequency {
+            Frequency::Daily if !self.by_day.is_empty() || !self.by_month_day.is_empty() => {
+                return invalid("daily rules take no byDay or byMonthDay");
+            }
+            Frequency::Weekly if !self.by_month_day.is_empty() => {
+                return invalid("weekly rules take byDay, not byMonthDay");
+            }
+            Frequency::Weekly if self.by_day.iter().any(|day| day.ordinal.is_some()) => {
+                return invalid("weekly byDay takes plain weekdays like MO, not 1MO");
+            }
+            Frequency::Yearly if !self.by_day.is_empty() || !self.by_month_day.is_empty() => {
+                return invalid("yearly rules repeat on the start date's month and day");
+            }
+            _ => {}
+        }
+        Ok(self)
+    }
+
+    /// Normalises `until` to a date or UTC instant, reading a floating time in `tz`.
+    fn resolve_until(mut self, tz: ServerTimezone) -> Result<Self, AppError> {
+        if let Some(until) = &self.until {
+            let until = Due::parse(until, tz).map_err(|_| {
+                AppError::invalid_field(
+                    "recurrence",
+                    format!("until '{until}' is not YYYY-MM-DD or RFC3339"),
+                )
+            })?;
+            self.until = Some(until.remindctl_arg());
+        }
+        Ok(self)
+    }
+
+    /// The rule with a floating `until` from remindctl read in the server timezone;
+    /// unreadable values are left as they were.
+    pub fn localized(self, tz: ServerTimezone) -> Self {
+        self.clone().resolve_until(tz).unwrap_or(self)
+    }
+
+    /// RFC 5545 form passed to `remindctl --recurrence`.
+    pub fn to_rrule(&self) -> String {
+        let mut parts = vec![format!("FREQ={}", self.frequency.as_rrule())];
+        if self.interval != 1 {
+            parts.push(format!("INTERVAL={}", self.interval));
+        }
+        if !self.by_day.is_empty() {
+            let days = self.by_day.iter().map(ByDay::to_string).collect::<Vec<_>>();
+            parts.push(format!("BYDAY={}", days.join(",")));
+        }
+        if !self.by_month_day.is_empty() {
+            let days = self
+                .by_month_day
+                .iter()
+                .map(i32::to_string)
+                .collect::<Vec<_>>();
+            parts.push(format!("BYMONTHDAY={}", days.join(",")));
+        }
+        if let Some(until) = &self.until {
+            parts.push(format!("UNTIL={}", until.replace(['-', ':'], "")));
+        }
+        if let Some(count) = self.count {
+            parts.push(format!("COUNT={count}"));
+        }
+        parts.join(";")
+    }
+
+    /// Occurrences on or after `start`, in order. `count` counts from `start`; those already
+    /// over at `after` are skipped but still counted. Times keep `start`'s wall-clock time in `tz`.
+    pub fn expand(
+        &self,
+        start: Due,
+        tz: ServerTimezone,
+        after: Option<DateTime<Utc>>,
+        limit: usize,
+    ) -> Vec<Due> {
+        let start_date = start.local_date(tz);
+        let time = match start {
+            Due::AllDay(_) => None,
+            Due::At(instant) => Some(tz.to_local(instant).time()),
+        };
+        let until = self
+            .until
+            .as_deref()
+            .and_then(|until| Due::parse(until, tz).ok());
+
+        let mut occurrences = Vec::new();
+        let mut produced = 0u32;
+        for period in 0..MAX_PERIODS {
+            for date in self.period_dates(start_date, period) {
+                if date < start_date {
+                    continue;
+                }
+                if self.count.is_some_and(|count| produced >= count) {
+                    return occurrences;
+                }
+                let Some(occurrence) = occurrence(date, time, tz) else {
+                    continue;
+                };
+                if until.is_some_and(|until| is_past(occurrence, until, tz)) {
+                    return occurrences;
+                }
+                produced += 1;
+                if after.is_some_and(|after| is_before(occurrence, after, tz)) {
+                    continue;
+                }
+                occurrences.push(occurrence);
+                if occurrences.len() >= limit {
+                    return occurrences;
+                }
+            }
+        }
+        occurrences
+    }
+
+    /// The occurrence after `current`, with `count` reduced by one, or `None` when the rule
+    /// is exhausted. Used to roll a completed recurring reminder forward.
+    pub fn advance(&self, current: Due, tz: ServerTimezone) -> Option<(Due, Self)> {
+        let next = self
+            .expand(current, tz, None, 2)
+            .into_iter()
+            .find(|due| *due != current)?;
+        let rest = Self {
+            count: self.count.map(|count| count.saturating_sub(1)),
+            ..self.clone()
+        };
+        Some((next, rest))
+    }
+
+    fn period_dates(&self, start: NaiveDate, period: u32) -> Vec<NaiveDate> {
+        let step = period.saturating_mul(self.interval);
+        let mut dates = match self.frequency {
+            Frequency::Daily => start
+                .checked_add_days(Days::new(u64::from(step)))
+                .into_iter()
+                .collect(),
+            Frequency::Weekly => {
+                let monday = start.week(Weekday::Mon).first_day();
+                let Some(week) = monday.checked_add_days(Days::new(u64::from(step) * 7)) else {
+                    return Vec::new();
+                };
+                let weekdays = if self.by_day.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_day.iter().map(|day| day.weekday).collect()
+                };
+                weekdays
+                    .into_iter()
+                    .filter_map(|weekday| {
+                        week.checked_add_days(Days::new(u64::from(weekday.num_days_from_monday())))
+                    })
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let Some(month) = start
+                    .with_day(1)
+                    .and_then(|first| first.checked_add_months(Months::new(step)))
+                else {
+                    return Vec::new();
+                };
+                self.month_dates(month, start.day())
+            }
+            Frequency::Yearly => start
+                .with_day(1)
+                .and_then(|first| first.checked_add_months(Months::new(step.saturating_mul(12))))
+                .and_then(|first| first.with_day(start.day()))
+                .into_iter()
+                .collect(),
+        };
+        dates.sort();
+        dates.dedup();
+        dates
+    }
+
+    /// Matching days in the month starting at `first`.
+    fn month_dates(&self, first: NaiveDate, start_day: u32) -> Vec<NaiveDate> {
+        let Some(next_month) = first.checked_add_months(Months::new(1)) else {
+            return Vec::new();
+        };
+        let days = next_month.signed_duration_since(first).num_days() as i32;
+        let mut dates = Vec::new();
+        if self.by_month_day.is_empty() && self.by_day.is_empty() {
+            dates.extend(first.with_day(start_day));
+        }
+        for day in &self.by_month_day {
+            let day = if *day < 0 { days + day + 1 } else { *day };
+            if (1..=days).contains(&day) {
+                dates.extend(first.with_day(day as u32));
+            }
+        }
+        for by_day in &self.by_day {
+            let matching = (0..days)
+                .filter_map(|offset| first.checked_add_days(Days::new(offset as u64)))
+                .filter(|date| date.weekday() == by_day.weekday)
+                .collect::<Vec<_>>();
+            match by_day.ordinal {
+                None => dates.extend(matching),
+                Some(ordinal) if ordinal > 0 => {
+                    dates.extend(matching.get(ordinal as usize - 1));
+                }
+                Some(ordinal) => {
+                    let from_end = matching.len().checked_sub(ordinal.unsigned_abs() as usize);
+                    dates.extend(from_end.and_then(|index| matching.get(index)));
+                }
+            }
+        }
+        dates
+    }
+}
+
+fn occurrence(date: NaiveDate, time: Option<NaiveTime>, tz: ServerTimezone) -> Option<Due> {
+    match time {
+        None => Some(Due::AllDay(date)),
+        Some(time) => tz.from_local(date.and_time(time)).map(Due::At),
+    }
+}
+
+/// Whether `occurrence` is already over at `after`; all-day ones last until their day ends.
+fn is_before(occurrence: Due, after: DateTime<Utc>, tz: ServerTimezone) -> bool {
+    match occurrence {
+        Due::AllDay(date) => date < tz.to_local(after).date_naive(),
+        Due::At(at) => at < after,
+    }
+}
+
+/// Whether `occurrence` falls after an inclusive `until` (an all-day `until` covers its whole day).
+fn is_past(occurrence: Due, until: Due, tz: ServerTimezone) -> bool {
+    match (occurrence, until) {
+        (_, Due::AllDay(last)) => occurrence.local_date(tz) > last,
+        (Due::AllDay(date), Due::At(_)) => date > until.local_date(tz),
+        (Due::At(at), Due::At(last)) => at > last,
+    }
+}
+
+/// `UNTIL` in RRULE's basic format, returned in the `YYYY-MM-DD` or UTC RFC3339 form.
+/// Anything else is kept as written for [`Recurrence::resolve_until`] to read in the
+/// server timezone.
+fn rrule_until(value: &str) -> Option<String> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+        return Some(date.format("%Y-%m-%d").to_string());
+    }
+    chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+        .ok()
+        .map(|naive| naive.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+        .or_else(|| (!value.is_empty()).then(|| value.to_owned()))
+}
+
+/// Reads `recurrence` from remindctl output: an RRULE string or the structured form.
+/// Anything else is logged and dropped rather than failing the whole listing. `until`
+/// stays as written until [`Recurrence::localized`].
+pub fn deserialize_lenient<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Recurrence>, D::Error> {
+    let value = Option::<Value>::deserialize(deserializer)?;
+    Ok(value.filter(|value| !value.is_null()).and_then(|value| {
+        Recurrence::read(&value)
+            .inspect_err(|err| {
+                tracing::warn!(recurrence = %value, error = %err, "ignoring unreadable recurrence");
+            })
+            .ok()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc() -> ServerTimezone {
+        ServerTimezone::Named(chrono_tz::UTC)
+    }
+
+    fn expand(rule: &str, start: &str, limit: usize) -> Result<Vec<String>, AppError> {
+        let rule = Recurrence::parse_rrule(rule, utc())?;
+        let start = Due::parse(start, utc())?;
+        Ok(rule
+            .expand(start, utc(), None, limit)
+            .iter()
+            .map(Due::remindctl_arg)
+            .collect())
+    }
+
+    #[test]
+    fn weekly_and_monthly_rules_expand_in_order() -> Result<(), AppError> {
+        assert_eq!(
+            expand("FREQ=WEEKLY;BYDAY=MO,TH", "2026-03-11T09:00:00Z", 3)?,
+            vec![
+                "2026-03-12T09:00:00Z",
+                "2026-03-16T09:00:00Z",
+                "2026-03-19T09:00:00Z"
+            ]
+        );
+        assert_eq!(
+            expand("RRULE:FREQ=MONTHLY;BYMONTHDAY=1,-1", "2026-01-15", 3)?,
+            vec!["2026-01-31", "2026-02-01", "2026-02-28"]
+        );
+        assert_eq!(
+            expand("FREQ=MONTHLY;BYDAY=-1FR;COUNT=2", "2026-03-01", 5)?,
+            vec!["2026-03-27", "2026-04-24"]
+        );
+        assert_eq!(
+            expand("FREQ=DAILY;INTERVAL=2;UNTIL=20260305", "2026-03-01", 5)?,
+            vec!["2026-03-01", "2026-03-03", "2026-03-05"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn structured_and_rrule_forms_round_trip() -> Result<(), AppError> {
+        let structured = Recurrence::from_input(
+            &serde_json::json!({
+                "frequency": "weekly", "interval": 2, "byDay": ["mo", "we"], "until": "2026-12-31"
+            }),
+            utc(),
+        )?;
+        assert_eq!(
+            structured.to_rrule(),
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;UNTIL=20261231"
+        );
+        assert_eq!(
+            Recurrence::parse_rrule(&structured.to_rrule(), utc())?,
+            structured
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn floating_until_is_read_in_the_server_timezone() -> Result<(), AppError> {
+        let madrid = ServerTimezone::Named(chrono_tz::Europe::Madrid);
+        let input = serde_json::json!({ "frequency": "daily", "until": "2026-05-01T09:00" });
+        let rule = Recurrence::from_input(&input, madrid)?;
+        assert_eq!(rule.until.as_deref(), Some("2026-05-01T07:00:00Z"));
+
+        let read: Recurrence = serde_json::from_value(input)?;
+        assert_eq!(read.localized(madrid), rule);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_rules_name_the_recurrence_field() {
+        for rule in [
+            "FREQ=HOURLY",
+            "FREQ=WEEKLY;BYDAY=XX",
+            "FREQ=DAILY;COUNT=2;UNTIL=20260101",
+            "INTERVAL=2",
+            "FREQ=MONTHLY;BYMONTHDAY=32",
+            "FREQ=DAILY;UNTIL=soon",
+            "FREQ=WEEKLY;BYDAY=ÖM",
+        ] {
+            let err = Recurrence::parse_rrule(rule, utc()).err();
+            assert_eq!(
+                err.as_ref().and_then(AppError::field),
+                Some("recurrence"),
+                "{rule}"
+            );
+        }
+        let structured = serde_json::json!({ "frequency": "weekly", "byDay": ["ÖM"] });
+        let err = Recurrence::from_input(&structured, utc()).err();
+        assert_eq!(err.as_ref().and_then(AppError::field), Some("recurrence"));
+    }
+
+    #[test]
+    fn advance_rolls_forward_until_count_is_used_up() -> Result<(), AppError> {
+        let rule = Recurrence::parse_rrule("FREQ=WEEKLY;COUNT=2", utc())?;
+        let first = Due::parse("2026-03-02", utc())?;
+        let (second, rest) = rule
+            .advance(first, utc())
+            .ok_or_else(|| AppError::invalid_input("rule ended early"))?;
+        assert_eq!(second.remindctl_arg(), "2026-03-09");
+        assert_eq!(rest.count, Some(1));
+        assert!(rest.advance(second, utc()).is_none());
+        Ok(())
+    }
+}
@@ -1,17 +1,80 @@
+use std::collections::HashMap;
 use std::process::Stdio;
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
-use serde::de::DeserializeOwned;
-use tokio::process::Command;
+use async_trait::async_trait;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::{Child, Command};
+use tokio::sync::{Semaphore, broadcast};
 use tokio::time;
+use tracing::{Instrument, Span};
 
+use crate::backend::{NewReminder, ReminderBackend, ReminderUpdate};
+use crate::capabilities::{Capabilities, PROBED_COMMANDS};
 use crate::error::AppError;
+use crate::fixtures::{Fixtures, RecordedCall};
+use crate::models::{RemindctlStatus, Reminder, ReminderList};
+use crate::resilience::{CircuitBreaker, RetryPolicy};
+use crate::schema::{Observed, SchemaDrift};
+
+const DEFAULT_MAX_CONCURRENT_READS: usize = 4;
+const SLOW_WAIT_MS: u64 = 100;
+const KILL_GRACE: Duration = Duration::from_secs(2);
+const MAX_STDOUT_BYTES: usize = 32 * 1024 * 1024;
+const MAX_STDERR_BYTES: usize = 64 * 1024;
+
+type ReadResult = Result<Arc<Vec<u8>>, AppError>;
+
+/// Reads currently running, keyed by the write generation they started in and full
+/// argv. Callers asking for the same argv subscribe to the running child's result
+/// instead of spawning another one, but never join a read that predates a write.
+type InflightReads = Mutex<HashMap<(u64, Vec<String>), broadcast::Sender<ReadResult>>>;
 
 #[derive(Debug, Clone)]
 pub struct RemindctlRunner {
     binary: String,
     read_timeout: Duration,
     write_timeout: Duration,
+    fixtures: Option<Arc<Fixtures>>,
+    inflight_reads: Arc<InflightReads>,
+    /// Bumped after every write, so reads that start later cannot reuse earlier ones.
+    write_generation: Arc<AtomicU64>,
+    read_permits: Arc<Semaphore>,
+    write_lock: Arc<tokio::sync::Mutex<()>>,
+    retry: RetryPolicy,
+    breaker: Arc<CircuitBreaker>,
+    capabilities: Arc<Capabilities>,
+    schema_drift: Arc<SchemaDrift>,
+}
+
+/// Whether a write can be replayed after a transient failure without changing its outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WriteKind {
+    Idempotent,
+    NonIdempotent,
+}
+
+enum ReadRole {
+    Leader(broadcast::Sender<ReadResult>),
+    Follower(broadcast::Receiver<ReadResult>),
+}
+
+/// Removes the leader's in-flight entry even if its future is dropped mid-read,
+/// so waiting followers see the channel close and retry instead of hanging.
+struct InflightGuard<'a> {
+    inflight: &'a InflightReads,
+    key: &'a (u64, Vec<String>),
+}
+
+impl Drop for InflightGuard<'_> {
+    fn drop(&mut self) {
+        self.inflight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(self.key);
+    }
 }
 
 impl RemindctlRunner {
@@ -20,36 +83,258 @@ impl RemindctlRunner {
             binary,
             read_timeout,
             write_timeout,
+            fixtures: None,
+            inflight_reads: Arc::new(Mutex::new(HashMap::new())),
+            write_generation: Arc::new(AtomicU64::new(0)),
+            read_permits: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_READS)),
+            write_lock: Arc::new(tokio::sync::Mutex::new(())),
+            retry: RetryPolicy::none(),
+            breaker: Arc::new(CircuitBreaker::new(0, Duration::ZERO)),
+            capabilities: Arc::new(Capabilities::unknown()),
+            schema_drift: Arc::new(SchemaDrift::new()),
+        }
+    }
+
+    pub fn with_schema_drift(mut self, schema_drift: Arc<SchemaDrift>) -> Self {
+        self.schema_drift = schema_drift;
+        self
+    }
+
+    pub fn with_capabilities(mut self, capabilities: Arc<Capabilities>) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Runs `--version`, `--help` and `<command> --help` directly, bypassing fixtures,
+    /// retries and the breaker. Replay mode has no binary to ask, so it stays unknown.
+    pub async fn probe_capabilities(&self) -> Capabilities {
+        if matches!(self.fixtures.as_deref(), Some(Fixtures::Replay(_))) {
+            return Capabilities::unknown();
+        }
+
+        let version = self
+            .probe_output(&["--version"])
+            .await
+            .and_then(|output| output.lines().next().map(|line| line.trim().to_owned()));
+        let top_help = self.probe_output(&["--help"]).await;
+        let mut command_helps = Vec::with_capacity(PROBED_COMMANDS.len());
+        for command in PROBED_COMMANDS {
+            if let Some(help) = self.probe_output(&[command, "--help"]).await {
+                command_helps.push((command, help));
+            }
         }
+
+        Capabilities::from_probe(version, top_help.as_deref(), &command_helps)
     }
 
-    pub async fn run_read_json<T>(&self, mut args: Vec<String>) -> Result<T, AppError>
+    async fn probe_output(&self, args: &[&str]) -> Option<String> {
+        let args = args.iter().map(|arg| (*arg).to_owned()).collect();
+        match self.spawn(args, self.read_timeout).await {
+            Ok(call) if call.success() => Some(format!("{}\n{}", call.stdout, call.stderr)),
+            Ok(call) => {
+                tracing::debug!(status = ?call.status, "remindctl capability probe failed");
+                None
+            }
+            Err(err) => {
+                tracing::debug!(error = %err, "remindctl capability probe failed");
+                None
+            }
+        }
+    }
+
+    /// Retries transient failures of reads and idempotent writes.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn with_circuit_breaker(mut self, breaker: Arc<CircuitBreaker>) -> Self {
+        self.breaker = breaker;
+        self
+    }
+
+    /// Caps how many read processes run at once. Writes always run one at a time.
+    pub fn with_max_concurrent_reads(mut self, max: usize) -> Self {
+        self.read_permits = Arc::new(Semaphore::new(max.max(1)));
+        self
+    }
+
+    pub fn with_fixtures(mut self, fixtures: Fixtures) -> Self {
+        self.fixtures = Some(Arc::new(fixtures));
+        self
+    }
+
+    async fn run_read_json<T>(&self, mut args: Vec<String>) -> Result<T, AppError>
     where
-        T: DeserializeOwned,
+        T: Observed,
     {
         append_safe_flags(&mut args);
-        let output = self.run(args, self.read_timeout).await?;
-        serde_json::from_slice::<T>(&output).map_err(AppError::from)
+        let output = self.run_coalesced(args).await?;
+        self.parse_observed(&output)
+    }
+
+    /// Parses through an untyped value first so key drift is recorded even when the
+    /// typed parse then fails on it.
+    fn parse_observed<T: Observed>(&self, output: &[u8]) -> Result<T, AppError> {
+        let value = serde_json::from_slice::<serde_json::Value>(output)?;
+        T::observe(&value, &self.schema_drift);
+        serde_json::from_value::<T>(value).map_err(AppError::from)
+    }
+
+    async fn run_coalesced(&self, args: Vec<String>) -> ReadResult {
+        loop {
+            let key = (self.write_generation.load(Ordering::SeqCst), args.clone());
+            let role = {
+                let mut inflight = self
+                    .inflight_reads
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                match inflight.get(&key) {
+                    Some(sender) => ReadRole::Follower(sender.subscribe()),
+                    None => {
+                        let (sender, _) = broadcast::channel(1);
+                        inflight.insert(key.clone(), sender.clone());
+                        ReadRole::Leader(sender)
+                    }
+                }
+            };
+
+            match role {
+                ReadRole::Follower(mut receiver) => {
+                    tracing::debug!(args = ?args, "joining in-flight remindctl read");
+                    if let Ok(result) = receiver.recv().await {
+                        return result;
+                    }
+                }
+                ReadRole::Leader(sender) => {
+                    let guard = InflightGuard {
+                        inflight: &self.inflight_reads,
+                        key: &key,
+                    };
+                    let result = self.run_read(args.clone()).await.map(Arc::new);
+                    drop(guard);
+                    let _ = sender.send(result.clone());
+                    return result;
+                }
+            }
+        }
     }
 
-    pub async fn run_write_json<T>(&self, mut args: Vec<String>) -> Result<T, AppError>
+    async fn run_write_json<T>(&self, mut args: Vec<String>, kind: WriteKind) -> Result<T, AppError>
     where
-        T: DeserializeOwned,
+        T: Observed,
     {
         append_safe_flags(&mut args);
-        let output = self.run(args, self.write_timeout).await?;
-        serde_json::from_slice::<T>(&output).map_err(AppError::from)
+        let output = self.run_write(args, kind).await?;
+        self.parse_observed(&output)
     }
 
-    pub async fn run_write_no_output(&self, mut args: Vec<String>) -> Result<(), AppError> {
+    async fn run_write_no_output(&self, mut args: Vec<String>) -> Result<(), AppError> {
         append_safe_flags(&mut args);
-        let _ = self.run(args, self.write_timeout).await?;
+        let _ = self.run_write(args, WriteKind::NonIdempotent).await?;
         Ok(())
     }
 
+    async fn run_read(&self, args: Vec<String>) -> Result<Vec<u8>, AppError> {
+        let span = tracing::info_span!(
+            "remindctl_read",
+            command = command_name(&args),
+            wait_ms = tracing::field::Empty,
+        );
+        async {
+            let started = Instant::now();
+            let _permit =
+                self.read_permits.acquire().await.map_err(|_| {
+                    AppError::CommandFailed("remindctl read limiter closed".to_owned())
+                })?;
+            record_wait(started);
+            self.run_with_retries(args, self.read_timeout, true).await
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn run_write(&self, args: Vec<String>, kind: WriteKind) -> Result<Vec<u8>, AppError> {
+        let span = tracing::info_span!(
+            "remindctl_write",
+            command = command_name(&args),
+            wait_ms = tracing::field::Empty,
+        );
+        async {
+            let started = Instant::now();
+            let _guard = self.write_lock.lock().await;
+            record_wait(started);
+            let result = self
+                .run_with_retries(args, self.write_timeout, kind == WriteKind::Idempotent)
+                .await;
+            // Even a failed write may have landed, so later reads start fresh.
+            self.write_generation.fetch_add(1, Ordering::SeqCst);
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Runs through the circuit breaker. Only transient failures count against it; a
+    /// clean remindctl error (unknown list, bad date) still proves the binary responds.
+    async fn run_with_retries(
+        &self,
+        args: Vec<String>,
+        timeout: Duration,
+        retryable: bool,
+    ) -> Result<Vec<u8>, AppError> {
+        let mut attempt = 0;
+        loop {
+            self.breaker.check()?;
+            let result = self.run(args.clone(), timeout).await;
+            match &result {
+                Err(err) if err.is_transient() => {
+                    self.breaker.record_failure();
+                    if !retryable || attempt >= self.retry.max_retries {
+                        return result;
+                    }
+                    let delay = self.retry.backoff(attempt);
+                    attempt += 1;
+                    tracing::warn!(
+                        attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        error = %err,
+                        "retrying remindctl call",
+                    );
+                    time::sleep(delay).await;
+                }
+                _ => {
+                    self.breaker.record_success();
+                    return result;
+                }
+            }
+        }
+    }
+
     async fn run(&self, args: Vec<String>, timeout: Duration) -> Result<Vec<u8>, AppError> {
+        let call = match self.fixtures.as_deref() {
+            Some(Fixtures::Replay(replayer)) => replayer.replay(&args)?,
+            fixtures => {
+                let call = self.spawn(args, timeout).await?;
+                if let Some(Fixtures::Record(recorder)) = fixtures
+                    && let Err(err) = recorder.record(&call)
+                {
+                    tracing::warn!(error = %err, "failed to record remindctl fixture");
+                }
+                call
+            }
+        };
+
+        if !call.success() {
+            return Err(classify_failure(call.status, &call.stderr));
+        }
+
+        Ok(call.stdout.into_bytes())
+    }
+
+    async fn spawn(&self, args: Vec<String>, timeout: Duration) -> Result<RecordedCall, AppError> {
         let mut cmd = Command::new(&self.binary);
-        cmd.args(args)
+        cmd.args(&args)
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -70,22 +355,479 @@ impl RemindctlRunner {
             }
         }
 
-        let child = cmd.spawn()?;
-        let output = time::timeout(timeout, child.wait_with_output())
+        // Own process group, so a timeout can signal anything remindctl spawned too.
+        #[cfg(unix)]
+        cmd.process_group(0);
+        cmd.kill_on_drop(true);
+
+        let mut child = cmd.spawn()?;
+        let pid = child.id();
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+
+        let outcome = time::timeout(timeout, async {
+            tokio::try_join!(
+                read_capped(stdout, MAX_STDOUT_BYTES),
+                read_capped(stderr, MAX_STDERR_BYTES),
+                child.wait(),
+            )
+        })
+        .await;
+
+        let (stdout, stderr, status) = match outcome {
+            Ok(result) => result?,
+            Err(_) => {
+                tracing::warn!(
+                    command = command_name(&args),
+                    timeout_ms = timeout.as_millis() as u64,
+                    "remindctl timed out, terminating",
+                );
+                terminate(&mut child, pid).await;
+                return Err(AppError::CommandTimeout);
+            }
+        };
+
+        if stdout.truncated {
+            return Err(AppError::CommandFailed(format!(
+                "remindctl output exceeded {MAX_STDOUT_BYTES} bytes"
+            )));
+        }
+
+        Ok(RecordedCall {
+            args,
+            status: status.code(),
+            stdout: String::from_utf8_lossy(&stdout.bytes).into_owned(),
+            stderr: String::from_utf8_lossy(&stderr.bytes).into_owned(),
+        })
+    }
+}
+
+#[async_trait]
+impl ReminderBackend for RemindctlRunner {
+    async fn status(&self) -> Result<RemindctlStatus, AppError> {
+        self.run_read_json(vec!["status".to_owned()]).await
+    }
+
+    async fn lists(&self) -> Result<Vec<ReminderList>, AppError> {
+        self.run_read_json(vec!["list".to_owned()]).await
+    }
+
+    async fn show(&self, filter: &str, list_name: Option<&str>) -> Result<Vec<Reminder>, AppError> {
+        self.run_read_json(show_args(filter, list_name)).await
+    }
+
+    async fn add(&self, reminder: NewReminder) -> Result<Reminder, AppError> {
+        if reminder.priority.is_some() {
+            self.capabilities.require("add", Some("--priority"))?;
+        }
+        if reminder.recurrence.is_some() {
+            self.capabilities.require("add", Some("--recurrence"))?;
+        }
+        if !reminder.alarms.is_empty() {
+            self.capabilities.require("add", Some("--alarm"))?;
+        }
+        self.run_write_json(add_args(reminder), WriteKind::NonIdempotent)
             .await
-            .map_err(|_| AppError::CommandTimeout)??;
+    }
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_owned();
-            return Err(AppError::CommandFailed(stderr));
+    async fn edit(&self, id: &str, update: ReminderUpdate) -> Result<Reminder, AppError> {
+        if update.clear_due {
+            self.capabilities.require("edit", Some("--clear-due"))?;
+        }
+        if update.priority.is_some() {
+            self.capabilities.require("edit", Some("--priority"))?;
+        }
+        if update.recurrence.is_some() {
+            self.capabilities.require("edit", Some("--recurrence"))?;
+        }
+        if update.clear_recurrence {
+            self.capabilities
+                .require("edit", Some("--clear-recurrence"))?;
+        }
+        if update.clear_alarms {
+            self.capabilities.require("edit", Some("--clear-alarms"))?;
         }
+        if !update.alarms.is_empty() {
+            self.capabilities.require("edit", Some("--alarm"))?;
+        }
+        // `--complete` advances a recurring reminder just like `complete` does.
+        let kind = if update.complete == Some(true) {
+            WriteKind::NonIdempotent
+        } else {
+            WriteKind::Idempotent
+        };
+        self.run_write_json(edit_args(id, update), kind).await
+    }
+
+    async fn complete(&self, ids: &[String], dry_run: bool) -> Result<Vec<Reminder>, AppError> {
+        let mut args = vec!["complete".to_owned()];
+        args.extend(ids.iter().cloned());
+        if dry_run {
+            self.capabilities.require("complete", Some("--dry-run"))?;
+            args.push("--dry-run".to_owned());
+        }
+        // Completing a recurring reminder advances its due date, so a retry whose first
+        // attempt landed would skip an occurrence.
+        let kind = if dry_run {
+            WriteKind::Idempotent
+        } else {
+            WriteKind::NonIdempotent
+        };
+        self.run_write_json(args, kind).await
+    }
+
+    async fn delete(&self, ids: &[String], dry_run: bool) -> Result<Vec<Reminder>, AppError> {
+        let mut args = vec!["delete".to_owned()];
+        args.extend(ids.iter().cloned());
+        if dry_run {
+            self.capabilities.require("delete", Some("--dry-run"))?;
+            args.push("--dry-run".to_owned());
+        } else {
+            self.capabilities.require("delete", Some("--force"))?;
+            args.push("--force".to_owned());
+        }
+        // A retried delete whose first attempt landed would fail with "not found".
+        let kind = if dry_run {
+            WriteKind::Idempotent
+        } else {
+            WriteKind::NonIdempotent
+        };
+        self.run_write_json(args, kind).await
+    }
+
+    async fn list_create(&self, name: &str) -> Result<(), AppError> {
+        self.capabilities.require("list", Some("--create"))?;
+        self.run_write_no_output(vec![
+            "list".to_owned(),
+            name.to_owned(),
+            "--create".to_owned(),
+        ])
+        .await
+    }
+
+    async fn list_rename(&self, name: &str, new_name: &str) -> Result<(), AppError> {
+        self.capabilities.require("list", Some("--rename"))?;
+        self.run_write_no_output(vec![
+            "list".to_owned(),
+            name.to_owned(),
+            "--rename".to_owned(),
+            new_name.to_owned(),
+        ])
+        .await
+    }
+
+    async fn list_delete(&self, name: &str) -> Result<(), AppError> {
+        self.capabilities.require("list", Some("--delete"))?;
+        self.run_write_no_output(vec![
+            "list".to_owned(),
+            name.to_owned(),
+            "--delete".to_owned(),
+            "--force".to_owned(),
+        ])
+        .await
+    }
+}
+
+struct CappedOutput {
+    bytes: Vec<u8>,
+    truncated: bool,
+}
+
+/// Buffers at most `cap` bytes but keeps draining the pipe, so a chatty child
+/// never blocks on a full pipe while we wait for it to exit.
+async fn read_capped<R>(pipe: Option<R>, cap: usize) -> std::io::Result<CappedOutput>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut output = CappedOutput {
+        bytes: Vec::new(),
+        truncated: false,
+    };
+    let Some(mut pipe) = pipe else {
+        return Ok(output);
+    };
+    let mut chunk = [0u8; 8192];
+    loop {
+        let read = pipe.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(output);
+        }
+        let room = cap.saturating_sub(output.bytes.len());
+        output.bytes.extend_from_slice(&chunk[..read.min(room)]);
+        output.truncated |= read > room;
+    }
+}
 
-        Ok(output.stdout)
+/// SIGTERM to the child's process group, then SIGKILL once `KILL_GRACE` runs out.
+/// Always reaps the direct child so no zombie is left behind.
+async fn terminate(child: &mut Child, pid: Option<u32>) {
+    #[cfg(unix)]
+    if let Some(pid) = pid {
+        signal_group(pid, libc::SIGTERM);
+        if time::timeout(KILL_GRACE, child.wait()).await.is_ok() && !group_alive(pid) {
+            return;
+        }
+        signal_group(pid, libc::SIGKILL);
+    }
+    #[cfg(not(unix))]
+    let _ = pid;
+
+    let _ = child.start_kill();
+    if let Err(err) = child.wait().await {
+        tracing::warn!(error = %err, "failed to reap timed-out remindctl");
+    }
+}
+
+#[cfg(unix)]
+fn signal_group(pgid: u32, signal: libc::c_int) {
+    // SAFETY: kill(2) only reads its arguments; a negative pid targets the process group.
+    unsafe {
+        libc::kill(-(pgid as libc::pid_t), signal);
     }
 }
 
+#[cfg(unix)]
+fn group_alive(pgid: u32) -> bool {
+    // SAFETY: signal 0 performs the permission/existence check without sending anything.
+    unsafe { libc::kill(-(pgid as libc::pid_t), 0) == 0 }
+}
+
+fn command_name(args: &[String]) -> &str {
+    args.first().map(String::as_str).unwrap_or_default()
+}
+
+fn record_wait(started: Instant) {
+    let wait_ms = started.elapsed().as_millis() as u64;
+    Span::current().record("wait_ms", wait_ms);
+    if wait_ms >= SLOW_WAIT_MS {
+        tracing::info!(wait_ms, "waited for remindctl slot");
+    } else {
+        tracing::debug!(wait_ms, "acquired remindctl slot");
+    }
+}
+
+/// Message prefixes remindctl starts an error line with, after an optional `Error:`.
+/// Anything else stays `CommandFailed`, so stray words in stderr are never retried.
+const NOT_AUTHORIZED_PREFIXES: [&str; 3] = [
+    "reminders access denied",
+    "reminders access not granted",
+    "not authorized to access reminders",
+];
+const LIST_NOT_FOUND_PREFIXES: [&str; 2] = ["list not found", "no list named"];
+const REMINDER_NOT_FOUND_PREFIXES: [&str; 2] = ["reminder not found", "no reminder with id"];
+const INVALID_DATE_PREFIXES: [&str; 3] =
+    ["invalid date", "could not parse date", "invalid due date"];
+const BUSY_PREFIXES: [&str; 2] = ["reminders database is busy", "reminders store is locked"];
+
+/// sysexits.h codes remindctl may exit with.
+const EX_TEMPFAIL: i32 = 75;
+const EX_NOPERM: i32 = 77;
+
+/// Maps a failed remindctl call to a typed error from its exit code and stderr.
+/// Unrecognized output stays `CommandFailed` with the raw stderr.
+fn classify_failure(status: Option<i32>, stderr: &str) -> AppError {
+    let stderr = stderr.trim();
+    if let Some(name) = message_after(stderr, &LIST_NOT_FOUND_PREFIXES) {
+        return AppError::ListNotFound(name.to_owned());
+    }
+    if let Some(id) = message_after(stderr, &REMINDER_NOT_FOUND_PREFIXES) {
+        return AppError::ReminderNotFound(id.to_owned());
+    }
+    if let Some(value) = message_after(stderr, &INVALID_DATE_PREFIXES) {
+        return AppError::InvalidDate(value.to_owned());
+    }
+    if status == Some(EX_NOPERM) || message_after(stderr, &NOT_AUTHORIZED_PREFIXES).is_some() {
+        return AppError::NotAuthorized(stderr.to_owned());
+    }
+    if status == Some(EX_TEMPFAIL) || message_after(stderr, &BUSY_PREFIXES).is_some() {
+        return AppError::Busy(stderr.to_owned());
+    }
+    AppError::CommandFailed(stderr.to_owned())
+}
+
+/// For the first stderr line starting with one of `prefixes` (any case), the subject
+/// after it as in "List not found: X", or the whole line when nothing follows.
+fn message_after<'a>(stderr: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    stderr.lines().find_map(|line| {
+        let line = line.trim();
+        let message = strip_prefix_ignore_case(line, "error:").map_or(line, str::trim_start);
+        prefixes.iter().find_map(|prefix| {
+            let detail = strip_prefix_ignore_case(message, prefix)?.trim_start_matches([':', ' ']);
+            Some(if detail.is_empty() { line } else { detail })
+        })
+    })
+}
+
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    text.get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &text[prefix.len()..])
+}
+
+fn show_args(filter: &str, list_name: Option<&str>) -> Vec<String> {
+    let mut args = vec!["show".to_owned(), filter.to_owned()];
+    if let Some(name) = list_name {
+        args.push("--list".to_owned());
+        args.push(name.to_owned());
+    }
+    args
+}
+
+fn add_args(reminder: NewReminder) -> Vec<String> {
+    let mut args = vec!["add".to_owned(), "--title".to_owned(), reminder.title];
+    if let Some(name) = reminder.list_name {
+        args.push("--list".to_owned());
+        args.push(name);
+    }
+    if let Some(due) = reminder.due {
+        args.push("--due".to_owned());
+        args.push(due);
+    }
+    if let Some(notes) = reminder.notes {
+        args.push("--notes".to_owned());
+        args.push(notes);
+    }
+    if let Some(priority) = reminder.priority {
+        args.push("--priority".to_owned());
+        args.push(priority.as_str().to_owned());
+    }
+    if let Some(recurrence) = reminder.recurrence {
+        args.push("--recurrence".to_owned());
+        args.push(recurrence.to_rrule());
+    }
+    for alarm in reminder.alarms {
+        args.push("--alarm".to_owned());
+        args.push(alarm.remindctl_arg());
+    }
+    args
+}
+
+fn edit_args(id: &str, update: ReminderUpdate) -> Vec<String> {
+    let mut args = vec!["edit".to_owned(), id.to_owned()];
+    if let Some(title) = update.title {
+        args.push("--title".to_owned());
+        args.push(title);
+    }
+    if let Some(name) = update.list_name {
+        args.push("--list".to_owned());
+        args.push(name);
+    }
+    if let Some(due) = update.due {
+        args.push("--due".to_owned());
+        args.push(due);
+    }
+    if update.clear_due {
+        args.push("--clear-due".to_owned());
+    }
+    if let Some(notes) = update.notes {
+        args.push("--notes".to_owned());
+        args.push(notes);
+    }
+    if let Some(priority) = update.priority {
+        args.push("--priority".to_owned());
+        args.push(priority.as_str().to_owned());
+    }
+    if let Some(recurrence) = update.recurrence {
+        args.push("--recurrence".to_owned());
+        args.push(recurrence.to_rrule());
+    }
+    if update.clear_recurrence {
+        args.push("--clear-recurrence".to_owned());
+    }
+    if update.clear_alarms {
+        args.push("--clear-alarms".to_owned());
+    }
+    for alarm in update.alarms {
+        args.push("--alarm".to_owned());
+        args.push(alarm.remindctl_arg());
+    }
+    if let Some(complete) = update.complete {
+        args.push(if complete {
+            "--complete".to_owned()
+        } else {
+            "--incomplete".to_owned()
+        });
+    }
+    args
+}
+
 fn append_safe_flags(args: &mut Vec<String>) {
     args.push("--json".to_owned());
     args.push("--no-input".to_owned());
     args.push("--no-color".to_owned());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_known_remindctl_failures() {
+        assert!(matches!(
+            classify_failure(Some(1), "Error: List not found: Compras\n"),
+            AppError::ListNotFound(name) if name == "Compras"
+        ));
+        assert!(matches!(
+            classify_failure(Some(1), "Reminders access denied"),
+            AppError::NotAuthorized(_)
+        ));
+        assert!(matches!(
+            classify_failure(Some(EX_TEMPFAIL), "something odd"),
+            AppError::Busy(_)
+        ));
+        let invalid_date = classify_failure(Some(1), "Invalid date: someday");
+        assert!(matches!(&invalid_date, AppError::InvalidDate(value) if value == "someday"));
+        assert_eq!(invalid_date.field(), None);
+        assert!(matches!(
+            classify_failure(Some(1), "boom"),
+            AppError::CommandFailed(message) if message == "boom"
+        ));
+    }
+
+    #[test]
+    fn stray_words_in_stderr_stay_command_failed() {
+        for stderr in [
+            "iCloud sync timed out; showing cached data",
+            "warning: no reminders matched, check permissions",
+            "Sync failed: authorization token expired for list not found in cache",
+        ] {
+            assert!(
+                matches!(
+                    classify_failure(Some(1), stderr),
+                    AppError::CommandFailed(_)
+                ),
+                "{stderr}"
+            );
+        }
+    }
+
+    #[test]
+    fn show_args_scope_to_list() {
+        let args = show_args("today", Some("Compras"));
+        assert_eq!(args, vec!["show", "today", "--list", "Compras"]);
+    }
+
+    #[test]
+    fn edit_args_follow_remindctl_flag_order() {
+        let args = edit_args(
+            "AAAA-1111",
+            ReminderUpdate {
+                title: Some("Pay rent".to_owned()),
+                clear_due: true,
+                complete: Some(false),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            args,
+            vec![
+                "edit",
+                "AAAA-1111",
+                "--title",
+                "Pay rent",
+                "--clear-due",
+                "--incomplete"
+            ]
+        );
+    }
+}
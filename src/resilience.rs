@@ -0,0 +1,175 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::AppError;
+use crate::models::BreakerStatus;
+
+/// How many times a transient failure is retried, and the base of the exponential backoff.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::ZERO,
+        }
+    }
+
+    /// Full jitter: a random delay in `[0, base * 2^attempt]`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let ceiling = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.min(16)));
+        let ceiling_ms = ceiling.as_millis() as u64;
+        if ceiling_ms == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_millis(random_u64() % (ceiling_ms + 1))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BreakerState {
+    Closed,
+    Open { until: Instant },
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct BreakerInner {
+    state: BreakerState,
+    consecutive_failures: u32,
+}
+
+/// Fails remindctl calls fast for a cool-down period after `threshold` consecutive
+/// transient failures. A zero threshold disables the breaker.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    inner: Mutex<BreakerInner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            inner: Mutex::new(BreakerInner {
+                state: BreakerState::Closed,
+                consecutive_failures: 0,
+            }),
+        }
+    }
+
+    /// Errors with `CircuitOpen` while cooling down; afterwards lets calls through half-open.
+    pub fn check(&self) -> Result<(), AppError> {
+        let Ok(mut inner) = self.inner.lock() else {
+            return Ok(());
+        };
+        if let BreakerState::Open { until } = inner.state {
+            let now = Instant::now();
+            if now < until {
+                return Err(AppError::CircuitOpen {
+                    retry_after_secs: (until - now).as_secs().max(1),
+                });
+            }
+            inner.state = BreakerState::HalfOpen;
+        }
+        Ok(())
+    }
+
+    pub fn record_success(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.state = BreakerState::Closed;
+            inner.consecutive_failures = 0;
+        }
+    }
+
+    pub fn record_failure(&self) {
+        if self.threshold == 0 {
+            return;
+        }
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+            if inner.state == BreakerState::HalfOpen || inner.consecutive_failures >= self.threshold
+            {
+                if !matches!(inner.state, BreakerState::Open { .. }) {
+                    tracing::warn!(
+                        consecutive_failures = inner.consecutive_failures,
+                        cooldown_secs = self.cooldown.as_secs(),
+                        "remindctl circuit opened",
+                    );
+                }
+                inner.state = BreakerState::Open {
+                    until: Instant::now() + self.cooldown,
+                };
+            }
+        }
+    }
+
+    pub fn status(&self) -> BreakerStatus {
+        let Ok(inner) = self.inner.lock() else {
+            return BreakerStatus {
+                state: "unknown".to_owned(),
+                consecutive_failures: 0,
+                retry_after_secs: None,
+            };
+        };
+        let (state, retry_after_secs) = match inner.state {
+            BreakerState::Closed => ("closed", None),
+            BreakerState::HalfOpen => ("half_open", None),
+            BreakerState::Open { until } => match until.checked_duration_since(Instant::now()) {
+                Some(remaining) => ("open", Some(remaining.as_secs().max(1))),
+                None => ("half_open", None),
+            },
+        };
+        BreakerStatus {
+            state: state.to_owned(),
+            consecutive_failures: inner.consecutive_failures,
+            retry_after_secs,
+        }
+    }
+}
+
+fn random_u64() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breaker_opens_after_threshold_and_half_opens_after_cooldown() {
+        let breaker = CircuitBreaker::new(2, Duration::from_millis(20));
+        breaker.record_failure();
+        assert!(breaker.check().is_ok());
+        breaker.record_failure();
+        assert!(matches!(breaker.check(), Err(AppError::CircuitOpen { .. })));
+        assert_eq!(breaker.status().state, "open");
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(breaker.check().is_ok());
+        breaker.record_failure();
+        assert!(breaker.check().is_err(), "a failed half-open probe reopens");
+    }
+
+    #[test]
+    fn backoff_stays_within_exponential_ceiling() {
+        let policy = RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+        };
+        for _ in 0..50 {
+            assert!(policy.backoff(0) <= Duration::from_millis(100));
+            assert!(policy.backoff(2) <= Duration::from_millis(400));
+        }
+    }
+}
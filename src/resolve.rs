@@ -1,5 +1,6 @@
 use crate::error::AppError;
 use crate::models::{Reminder, ReminderList};
+use crate::text::fold;
 
 #[derive(Debug, Clone)]
 pub struct ReminderResolution {
@@ -9,19 +10,22 @@ pub struct ReminderResolution {
 
 pub fn validate_text_input(value: &str, field_name: &str, max_len: usize) -> Result<(), AppError> {
     if value.is_empty() {
-        return Err(AppError::invalid_input(format!(
-            "{field_name} cannot be empty"
-        )));
+        return Err(AppError::invalid_field(
+            field_name,
+            format!("{field_name} cannot be empty"),
+        ));
     }
     if value.chars().count() > max_len {
-        return Err(AppError::invalid_input(format!(
-            "{field_name} exceeds max length {max_len}"
-        )));
+        return Err(AppError::invalid_field(
+            field_name,
+            format!("{field_name} exceeds max length {max_len}"),
+        ));
     }
     if value.chars().any(|ch| ch.is_control()) {
-        return Err(AppError::invalid_input(format!(
-            "{field_name} contains control characters"
-        )));
+        return Err(AppError::invalid_field(
+            field_name,
+            format!("{field_name} contains control characters"),
+        ));
     }
     Ok(())
 }
@@ -36,7 +40,7 @@ pub fn resolve_list_name(
             let list = lists
                 .iter()
                 .find(|list| list.id.eq_ignore_ascii_case(id))
-                .ok_or_else(|| AppError::invalid_input("list_id not found"))?;
+                .ok_or_else(|| AppError::ListNotFound(id.to_owned()))?;
             if list.title != name {
                 return Err(AppError::invalid_input(
                     "list_id and list_name refer to different lists",
@@ -48,17 +52,155 @@ pub fn resolve_list_name(
             let list = lists
                 .iter()
                 .find(|list| list.id.eq_ignore_ascii_case(id))
-                .ok_or_else(|| AppError::invalid_input("list_id not found"))?;
+                .ok_or_else(|| AppError::ListNotFound(id.to_owned()))?;
             Ok(Some(list.title.clone()))
         }
         (None, Some(name)) => {
-            validate_text_input(name, "list_name", 120)?;
+            validate_text_input(name, "listName", 120)?;
             Ok(Some(name.to_owned()))
         }
         (None, None) => Ok(None),
     }
 }
 
+/// List references in one read: IDs and names, with the wire names of the fields they
+/// came from for error messages.
+#[derive(Debug, Clone, Copy)]
+pub struct ListRefs<'a> {
+    pub ids: &'a [String],
+    pub names: &'a [String],
+    pub id_field: &'static str,
+    pub name_field: &'static str,
+}
+
+/// The lists a read covers: `include` (every list when empty) minus `exclude`.
+#[derive(Debug, Clone, Default)]
+pub struct ListScope {
+    include: Vec<ReminderList>,
+    exclude: Vec<ReminderList>,
+    list_name: Option<String>,
+}
+
+impl ListScope {
+    /// Resolves the array forms against `lists`, failing once with every unknown
+    /// reference. `single` is the already-resolved `listId`/`listName`; on its own it
+    /// stays a plain `--list` name exactly as before.
+    pub fn resolve(
+        lists: &[ReminderList],
+        single: Option<String>,
+        include: ListRefs<'_>,
+        exclude: ListRefs<'_>,
+    ) -> Result<Self, AppError> {
+        let no_arrays = [include.ids, include.names, exclude.ids, exclude.names]
+            .iter()
+            .all(|refs| refs.is_empty());
+        if no_arrays {
+            return Ok(Self {
+                list_name: single,
+                ..Self::default()
+            });
+        }
+
+        let mut unknown = Vec::new();
+        let single = single.map(|name| vec![name]).unwrap_or_default();
+        let mut included = find_lists(lists, &[], &single, ("listId", "listName"), &mut unknown)?;
+        included.extend(find_lists(
+            lists,
+            include.ids,
+            include.names,
+            (include.id_field, include.name_field),
+            &mut unknown,
+        )?);
+        let excluded = find_lists(
+            lists,
+            exclude.ids,
+            exclude.names,
+            (exclude.id_field, exclude.name_field),
+            &mut unknown,
+        )?;
+        if !unknown.is_empty() {
+            let known = lists
+                .iter()
+                .map(|list| list.title.as_str())
+                .collect::<Vec<_>>();
+            return Err(AppError::ListNotFound(format!(
+                "{}; available lists: {}",
+                unknown.join(", "),
+                known.join(", ")
+            )));
+        }
+
+        let mut include = Vec::<ReminderList>::new();
+        for list in included {
+            if !include.iter().any(|seen| seen.id == list.id) {
+                include.push(list);
+            }
+        }
+        let list_name = match (include.as_slice(), excluded.is_empty()) {
+            ([only], true) => Some(only.title.clone()),
+            _ => None,
+        };
+        Ok(Self {
+            include,
+            exclude: excluded,
+            list_name,
+        })
+    }
+
+    /// The `--list` to pass to `show`, when the scope is exactly one list.
+    pub fn list_name(&self) -> Option<&str> {
+        self.list_name.as_deref()
+    }
+
+    /// Keeps reminders in scope, grouped by list in the order the lists were given and
+    /// in backend order within each list.
+    pub fn apply(&self, reminders: &mut Vec<Reminder>) {
+        let position = |reminder: &Reminder, lists: &[ReminderList]| {
+            lists.iter().position(|list| {
+                list.id.eq_ignore_ascii_case(&reminder.list_id)
+                    || (reminder.list_id.is_empty() && list.title == reminder.list_name)
+            })
+        };
+        reminders.retain(|reminder| {
+            (self.include.is_empty() || position(reminder, &self.include).is_some())
+                && position(reminder, &self.exclude).is_none()
+        });
+        if self.include.len() > 1 {
+            reminders.sort_by_key(|reminder| position(reminder, &self.include));
+        }
+    }
+}
+
+/// Lists matching `ids` (case-insensitively) and `names` (exactly, else ignoring case
+/// and accents), noting each reference that matches nothing in `unknown`.
+fn find_lists(
+    lists: &[ReminderList],
+    ids: &[String],
+    names: &[String],
+    (id_field, name_field): (&str, &str),
+    unknown: &mut Vec<String>,
+) -> Result<Vec<ReminderList>, AppError> {
+    let mut found = Vec::new();
+    for id in ids {
+        match lists.iter().find(|list| list.id.eq_ignore_ascii_case(id)) {
+            Some(list) => found.push(list.clone()),
+            None => unknown.push(format!("{id_field} '{id}'")),
+        }
+    }
+    for name in names {
+        validate_text_input(name, name_field, 120)?;
+        let list = lists
+            .iter()
+            .find(|list| list.title == *name)
+            .or_else(|| lists.iter().find(|list| fold(&list.title) == fold(name)));
+        match list {
+            Some(list) => found.push(list.clone()),
+            None => unknown.push(format!("{name_field} '{name}'")),
+        }
+    }
+    Ok(found)
+}
+
 pub fn resolve_reminder_ids(
     reminders: &[Reminder],
     raw_ids: &[String],
@@ -85,22 +227,14 @@ pub fn resolve_reminder_ids(
                     .to_ascii_lowercase()
                     .starts_with(&raw_id.to_ascii_lowercase())
             })
-            .map(|reminder| reminder.id.clone())
             .collect::<Vec<_>>();
 
-        match matches.len() {
-            0 => {
-                return Err(AppError::invalid_input(format!(
-                    "reminder ref '{raw_id}' not found"
-                )));
-            }
-            1 => resolved.push(matches[0].clone()),
-            _ => {
-                let candidates = matches.join(", ");
-                return Err(AppError::invalid_input(format!(
-                    "reminder ref '{raw_id}' is ambiguous, candidates: {candidates}"
-                )));
+        match matches.as_slice() {
+            [] => {
+                return Err(AppError::ReminderNotFound(raw_id.clone()));
             }
+            [only] => resolved.push(only.id.clone()),
+            _ => return Err(ambiguous_ref(raw_id, &matches)),
         }
     }
 
@@ -134,18 +268,12 @@ pub fn resolve_reminder_ids_lenient(
                     .to_ascii_lowercase()
                     .starts_with(&raw_id.to_ascii_lowercase())
             })
-            .map(|reminder| reminder.id.clone())
             .collect::<Vec<_>>();
 
-        match matches.len() {
-            0 => missing.push(raw_id.clone()),
-            1 => resolved.push(matches[0].clone()),
-            _ => {
-                let candidates = matches.join(", ");
-                return Err(AppError::invalid_input(format!(
-                    "reminder ref '{raw_id}' is ambiguous, candidates: {candidates}"
-                )));
-            }
+        match matches.as_slice() {
+            [] => missing.push(raw_id.clone()),
+            [only] => resolved.push(only.id.clone()),
+            _ => return Err(ambiguous_ref(raw_id, &matches)),
         }
     }
 
@@ -155,9 +283,17 @@ pub fn resolve_reminder_ids_lenient(
     })
 }
 
+fn ambiguous_ref(raw_id: &str, matches: &[&Reminder]) -> AppError {
+    AppError::AmbiguousRef {
+        reference: raw_id.to_owned(),
+        candidates: matches.iter().map(|reminder| (*reminder).into()).collect(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::priority::Priority;
 
     fn mk_reminder(id: &str) -> Reminder {
         Reminder {
@@ -166,15 +302,31 @@ mod tests {
             list_id: "l1".to_owned(),
             list_name: "Reminders".to_owned(),
             is_completed: false,
-            priority: "none".to_owned(),
-            due_date: None,
-            notes: String::new(),
+            priority: Priority::None,
+            ..Reminder::default()
         }
     }
 
+    #[test]
+    fn ambiguous_prefix_lists_candidates() -> Result<(), String> {
+        let reminders = vec![mk_reminder("AAAA-1111"), mk_reminder("AAAA-2222")];
+        let error = resolve_reminder_ids(&reminders, &["aaaa".to_owned()])
+            .err()
+            .ok_or("ambiguous prefix should fail")?;
+
+        let ids = error
+            .candidates()
+            .iter()
+            .map(|candidate| candidate.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(error.code(), "ambiguous_ref");
+        assert_eq!(ids, vec!["AAAA-1111", "AAAA-2222"]);
+        Ok(())
+    }
+
     #[test]
     fn allows_emoji_list_names() {
-        let result = validate_text_input("Reminders ⚠️", "list_name", 120);
+        let result = validate_text_input("Reminders ⚠️", "listName", 120);
         assert!(result.is_ok(), "emoji list names should be valid");
     }
 
@@ -189,4 +341,56 @@ mod tests {
         assert_eq!(result.missing_refs, vec!["BBBB".to_owned()]);
         Ok(())
     }
+
+    fn refs<'a>(ids: &'a [String], names: &'a [String]) -> ListRefs<'a> {
+        ListRefs {
+            ids,
+            names,
+            id_field: "listIds",
+            name_field: "listNames",
+        }
+    }
+
+    #[test]
+    fn list_scopes_merge_in_given_order_and_report_every_unknown_list() -> Result<(), String> {
+        let lists = [("L-1", "Work"), ("L-2", "Errands"), ("L-3", "Compras")].map(|(id, title)| {
+            ReminderList {
+                id: id.to_owned(),
+                title: title.to_owned(),
+                reminder_count: None,
+                overdue_count: None,
+            }
+        });
+        let mut reminders = ["L-1", "L-2", "L-3", "L-1"]
+            .iter()
+            .enumerate()
+            .map(|(index, list)| Reminder {
+                list_id: (*list).to_owned(),
+                ..mk_reminder(&format!("R-{index}"))
+            })
+            .collect::<Vec<_>>();
+        let names = ["errands".to_owned(), "Work".to_owned()];
+        let scope = ListScope::resolve(&lists, None, refs(&[], &names), refs(&[], &[]))
+            .map_err(|error| error.to_string())?;
+        assert_eq!(scope.list_name(), None);
+        scope.apply(&mut reminders);
+        let ids = reminders.iter().map(|r| r.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["R-1", "R-0", "R-3"]);
+
+        let error = ListScope::resolve(
+            &lists,
+            None,
+            refs(&["L-9".to_owned()], &[]),
+            refs(&[], &["Groceries".to_owned()]),
+        )
+        .err()
+        .ok_or("unknown lists should fail")?;
+        assert_eq!(error.code(), "list_not_found");
+        let message = error.to_string();
+        assert!(
+            message.contains("listIds 'L-9'") && message.contains("listNames 'Groceries'"),
+            "{message}"
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,164 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Mutex, PoisonError};
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::models::{ModelDriftReport, RemindctlStatus, Reminder, ReminderList, SchemaDriftReport};
+
+/// A remindctl output type whose JSON keys are checked against its model before parsing.
+pub trait Observed: DeserializeOwned {
+    fn observe(value: &Value, drift: &SchemaDrift);
+}
+
+impl Observed for Reminder {
+    fn observe(value: &Value, drift: &SchemaDrift) {
+        drift.observe_object::<Self>(value);
+    }
+}
+
+impl Observed for ReminderList {
+    fn observe(value: &Value, drift: &SchemaDrift) {
+        drift.observe_object::<Self>(value);
+    }
+}
+
+impl Observed for RemindctlStatus {
+    fn observe(value: &Value, drift: &SchemaDrift) {
+        drift.observe_object::<Self>(value);
+    }
+}
+
+impl<T: Observed> Observed for Vec<T> {
+    fn observe(value: &Value, drift: &SchemaDrift) {
+        for item in value.as_array().into_iter().flatten() {
+            T::observe(item, drift);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ModelDrift {
+    expected: BTreeSet<String>,
+    required: BTreeSet<String>,
+    observed: u64,
+    unknown: BTreeMap<String, u64>,
+    missing: BTreeMap<String, u64>,
+}
+
+/// Unknown and missing keys seen in remindctl output, per model. Each new key is
+/// logged once; counts keep growing and are served as a diagnostics resource.
+#[derive(Debug, Default)]
+pub struct SchemaDrift {
+    models: Mutex<BTreeMap<String, ModelDrift>>,
+}
+
+impl SchemaDrift {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn observe_object<T: JsonSchema>(&self, value: &Value) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        let name = T::schema_name();
+        let mut models = self.models.lock().unwrap_or_else(PoisonError::into_inner);
+        let model = models
+            .entry(name.to_string())
+            .or_insert_with(model_drift::<T>);
+        model.observed += 1;
+
+        for key in object.keys().filter(|key| !model.expected.contains(*key)) {
+            let count = model.unknown.entry(key.clone()).or_default();
+            if *count == 0 {
+                tracing::warn!(model = %name, key, "remindctl output has an unknown key");
+            }
+            *count += 1;
+        }
+
+        for key in model
+            .expected
+            .iter()
+            .filter(|key| !object.contains_key(*key))
+        {
+            let count = model.missing.entry(key.clone()).or_default();
+            if *count == 0 {
+                if model.required.contains(key) {
+                    tracing::warn!(model = %name, key, "remindctl output is missing a required key");
+                } else {
+                    tracing::debug!(model = %name, key, "remindctl output omits an optional key");
+                }
+            }
+            *count += 1;
+        }
+    }
+
+    pub fn report(&self) -> SchemaDriftReport {
+        let models = self.models.lock().unwrap_or_else(PoisonError::into_inner);
+        SchemaDriftReport {
+            models: models
+                .iter()
+                .map(|(name, model)| {
+                    let (missing_required, missing_optional) = model
+                        .missing
+                        .iter()
+                        .map(|(key, count)| (key.clone(), *count))
+                        .partition(|(key, _)| model.required.contains(key));
+                    ModelDriftReport {
+                        model: name.clone(),
+                        observed: model.observed,
+                        unknown_keys: model.unknown.clone(),
+                        missing_required_keys: missing_required,
+                        missing_optional_keys: missing_optional,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+fn model_drift<T: JsonSchema>() -> ModelDrift {
+    let schema = schemars::schema_for!(T);
+    let keys = |name: &str| -> BTreeSet<String> {
+        match schema.get(name) {
+            Some(Value::Object(properties)) => properties.keys().cloned().collect(),
+            Some(Value::Array(required)) => required
+                .iter()
+                .filter_map(|key| key.as_str().map(str::to_owned))
+                .collect(),
+            _ => BTreeSet::new(),
+        }
+    };
+    ModelDrift {
+        expected: keys("properties"),
+        required: keys("required"),
+        ..ModelDrift::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_unknown_and_missing_keys_per_model() {
+        let drift = SchemaDrift::new();
+        let payload = serde_json::json!([
+            { "id": "A", "title": "One", "listID": "L", "listName": "Inbox",
+              "isCompleted": false, "priority": "none", "hasAlarms": true },
+            { "id": "B", "name": "Two", "listID": "L", "listName": "Inbox",
+              "isCompleted": false, "priority": "none", "dueDate": null, "notes": "" }
+        ]);
+        Vec::<Reminder>::observe(&payload, &drift);
+
+        let report = drift.report();
+        let reminder = &report.models[0];
+        assert_eq!(reminder.observed, 2);
+        assert_eq!(reminder.unknown_keys.get("hasAlarms"), Some(&1));
+        assert_eq!(reminder.unknown_keys.get("name"), Some(&1));
+        assert_eq!(reminder.missing_required_keys.get("title"), Some(&1));
+        assert_eq!(reminder.missing_optional_keys.get("notes"), Some(&1));
+    }
+}
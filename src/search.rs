@@ -0,0 +1,298 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::models::{Highlight, Reminder, SearchHit};
+use crate::text;
+
+/// Title matches count this much more than notes matches.
+const TITLE_WEIGHT: f64 = 2.0;
+const NOTES_WEIGHT: f64 = 1.0;
+/// Bonus when the whole query appears as written (after folding) in the title.
+const PHRASE_BONUS: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchKind {
+    /// A whole word equal to the term.
+    Exact,
+    /// A word starting with the term.
+    Prefix,
+    /// The term inside a longer word.
+    Substring,
+    /// A word within a small edit distance of the term.
+    Fuzzy,
+}
+
+impl MatchKind {
+    fn score(self, distance: usize) -> f64 {
+        match self {
+            Self::Exact => 1.0,
+            Self::Prefix => 0.8,
+            Self::Substring => 0.6,
+            Self::Fuzzy => 0.5 - 0.1 * distance.saturating_sub(1) as f64,
+        }
+    }
+}
+
+/// Text lowercased with accents stripped, remembering which original character each
+/// folded character came from so matches can be highlighted in the original.
+struct Folded {
+    chars: Vec<char>,
+    origin: Vec<usize>,
+    words: Vec<Range<usize>>,
+}
+
+impl Folded {
+    fn new(text: &str) -> Self {
+        let mut chars = Vec::new();
+        let mut origin = Vec::new();
+        for (index, ch) in text.chars().enumerate() {
+            for folded in text::fold_char(ch) {
+                chars.push(folded);
+                origin.push(index);
+            }
+        }
+        let mut words = Vec::new();
+        let mut start = None;
+        for (index, ch) in chars.iter().enumerate() {
+            match (ch.is_alphanumeric(), start) {
+                (true, None) => start = Some(index),
+                (false, Some(begin)) => {
+                    words.push(begin..index);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(begin) = start {
+            words.push(begin..chars.len());
+        }
+        Self {
+            chars,
+            origin,
+            words,
+        }
+    }
+
+    /// The original-text character range a folded range came from.
+    fn original(&self, range: &Range<usize>) -> Range<usize> {
+        self.origin[range.start]..self.origin[range.end - 1] + 1
+    }
+
+    /// Best match for `term`, preferring exact over prefix over fuzzy, then the earliest.
+    /// Substrings inside longer words are only tried when no word matches.
+    fn best_match(&self, term: &[char]) -> Option<(MatchKind, f64, Range<usize>)> {
+        let mut candidates = Vec::new();
+        for word in &self.words {
+            let text = &self.chars[word.clone()];
+            if text == term {
+                candidates.push((MatchKind::Exact, 0, word.clone()));
+            } else if text.starts_with(term) && term.len() >= 2 {
+                candidates.push((MatchKind::Prefix, 0, word.start..word.start + term.len()));
+            } else if let Some(distance) = within_typo_budget(term, text) {
+                candidates.push((MatchKind::Fuzzy, distance, word.clone()));
+            }
+        }
+        if candidates.is_empty()
+            && term.len() >= 3
+            && let Some(start) = self
+                .chars
+                .windows(term.len())
+                .position(|window| window == term)
+        {
+            candidates.push((MatchKind::Substring, 0, start..start + term.len()));
+        }
+        candidates
+            .into_iter()
+            .map(|(kind, distance, range)| (kind, kind.score(distance), range))
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })
+    }
+
+    fn contains(&self, needle: &[char]) -> bool {
+        !needle.is_empty()
+            && self
+                .chars
+                .windows(needle.len())
+                .any(|window| window == needle)
+    }
+}
+
+/// Edit distance allowed for a term: none for short words, where a typo is as likely to
+/// be a different word, then one, then two.
+fn typo_budget(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+fn within_typo_budget(term: &[char], word: &[char]) -> Option<usize> {
+    let budget = typo_budget(term.len());
+    if budget == 0 || term.len().abs_diff(word.len()) > budget {
+        return None;
+    }
+    let distance = edit_distance(term, word);
+    (distance <= budget).then_some(distance)
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent transpositions ("mlik").
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut value = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                value = value.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = value;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Folded query words. Errors name the `query` field when nothing searchable is left.
+pub fn query_terms(query: &str) -> Result<Vec<Vec<char>>, AppError> {
+    let folded = Folded::new(query);
+    let terms = folded
+        .words
+        .iter()
+        .map(|word| folded.chars[word.clone()].to_vec())
+        .collect::<Vec<_>>();
+    if terms.is_empty() {
+        return Err(AppError::invalid_field(
+            "query",
+            "query needs at least one letter or digit",
+        ));
+    }
+    Ok(terms)
+}
+
+/// Reminders whose title or notes match every query term, best first. Ties fall back
+/// to title, then ID, so the order is stable between calls.
+pub fn search(reminders: Vec<Reminder>, query: &str) -> Result<Vec<SearchHit>, AppError> {
+    let terms = query_terms(query)?;
+    let phrase = Folded::new(query.trim()).chars;
+    let mut hits = reminders
+        .into_iter()
+        .filter_map(|reminder| score(reminder, &terms, &phrase))
+        .collect::<Vec<_>>();
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.reminder.title.cmp(&b.reminder.title))
+            .then_with(|| a.reminder.id.cmp(&b.reminder.id))
+    });
+    Ok(hits)
+}
+
+fn score(reminder: Reminder, terms: &[Vec<char>], phrase: &[char]) -> Option<SearchHit> {
+    let title = Folded::new(&reminder.title);
+    let notes = Folded::new(&reminder.notes);
+    let mut total = 0.0;
+    let mut highlights = Vec::new();
+    for term in terms {
+        let in_title = title
+            .best_match(term)
+            .map(|(kind, score, range)| (kind, score * TITLE_WEIGHT, range, "title"));
+        let in_notes = notes
+            .best_match(term)
+            .map(|(kind, score, range)| (kind, score * NOTES_WEIGHT, range, "notes"));
+        let (kind, term_score, range, field) = match (in_title, in_notes) {
+            (Some(title_match), Some(notes_match)) if notes_match.1 > title_match.1 => notes_match,
+            (Some(title_match), _) => title_match,
+            (None, Some(notes_match)) => notes_match,
+            (None, None) => return None,
+        };
+        total += term_score;
+        let (source, folded) = match field {
+            "title" => (&reminder.title, &title),
+            _ => (&reminder.notes, &notes),
+        };
+        let span = folded.original(&range);
+        highlights.push(Highlight {
+            field: field.to_owned(),
+            start: span.start,
+            end: span.end,
+            text: source.chars().skip(span.start).take(span.len()).collect(),
+            term: term.iter().collect(),
+            kind,
+        });
+    }
+    if terms.len() > 1 && title.contains(phrase) {
+        total += PHRASE_BONUS;
+    }
+    highlights.sort_by(|a, b| a.field.cmp(&b.field).reverse().then(a.start.cmp(&b.start)));
+    highlights.dedup_by(|a, b| a.field == b.field && a.start == b.start && a.end == b.end);
+    Some(SearchHit {
+        score: (total * 100.0).round() / 100.0,
+        highlights,
+        reminder,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reminder(id: &str, title: &str, notes: &str) -> Reminder {
+        Reminder {
+            id: id.to_owned(),
+            title: title.to_owned(),
+            notes: notes.to_owned(),
+            ..Reminder::default()
+        }
+    }
+
+    fn ranked(query: &str, reminders: Vec<Reminder>) -> Result<Vec<String>, AppError> {
+        Ok(search(reminders, query)?
+            .into_iter()
+            .map(|hit| hit.reminder.id)
+            .collect())
+    }
+
+    #[test]
+    fn folds_accents_and_case_and_highlights_the_original_text() -> Result<(), AppError> {
+        let hits = search(vec![reminder("A", "Comprar CAFÉ molido", "")], "cafe")?;
+        let highlight = hits.first().and_then(|hit| hit.highlights.first());
+        assert_eq!(
+            highlight.map(|h| (h.field.as_str(), h.start, h.end, h.text.as_str(), h.kind)),
+            Some(("title", 8, 12, "CAFÉ", MatchKind::Exact))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tolerates_typos_and_ranks_title_over_notes() -> Result<(), AppError> {
+        let reminders = vec![
+            reminder("A", "Call the bank", "ask about the mortgage"),
+            reminder("B", "Mortgage paperwork", ""),
+            reminder("C", "Buy milk", ""),
+        ];
+        assert_eq!(ranked("morgage", reminders.clone())?, vec!["B", "A"]);
+        assert_eq!(ranked("mlik", reminders.clone())?, vec!["C"]);
+        assert!(ranked("bank mortgage", reminders.clone())?.contains(&"A".to_owned()));
+        assert!(ranked("zebra", reminders)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn empty_queries_name_the_query_field() {
+        let err = search(Vec::new(), " ¿? ").err();
+        assert_eq!(err.as_ref().and_then(AppError::field), Some("query"));
+    }
+}
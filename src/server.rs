@@ -1,21 +1,28 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Instant;
 
+use axum::Router;
 use axum::body::Body;
 use axum::extract::State;
 use axum::http::{HeaderMap, Request, StatusCode};
-use axum::middleware::Next;
+use axum::middleware::{self, Next};
 use axum::response::Response;
+use chrono::Utc;
 use rmcp::handler::server::router::tool::ToolRouter;
+use rmcp::handler::server::tool::schema_for_output;
 use rmcp::handler::server::wrapper::Parameters;
+use rmcp::transport::StreamableHttpServerConfig;
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::streamable_http_server::tower::StreamableHttpService;
 use rmcp::{
     ErrorData as McpError, Json, RoleServer, ServerHandler,
     model::{
-        AnnotateAble, InitializeRequestParams, InitializeResult, ListResourceTemplatesResult,
-        ListResourcesResult, PaginatedRequestParams, RawResourceTemplate,
-        ReadResourceRequestParams, ReadResourceResult, ResourceContents, ResourceTemplate,
-        ServerCapabilities, ServerInfo,
+        AnnotateAble, CallToolResult, Content, InitializeRequestParams, InitializeResult,
+        IntoContents, JsonObject, ListResourceTemplatesResult, ListResourcesResult,
+        PaginatedRequestParams, RawResourceTemplate, ReadResourceRequestParams, ReadResourceResult,
+        ResourceContents, ResourceTemplate, ServerCapabilities, ServerInfo,
     },
     service::RequestContext,
     tool, tool_handler, tool_router,
@@ -23,37 +30,125 @@ use rmcp::{
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-
-use crate::config::Config;
+use tokio_util::sync::CancellationToken;
+
+use crate::alarms::{Alarm, AlarmInput};
+use crate::backend::{NewReminder, ReminderBackend, ReminderUpdate};
+use crate::cache::{CachingBackend, SnapshotCache};
+use crate::capabilities::Capabilities;
+use crate::config::{BackendKind, Config, FixtureMode};
+use crate::dates::{self, Due};
 use crate::error::AppError;
+use crate::fixtures::{FixtureRecorder, FixtureReplayer, Fixtures};
+use crate::memory::MemoryBackend;
 use crate::models::{
-    BatchActionResult, BatchProcessResult, DeleteResult, ListDeleteResult, ListsResult,
-    RemindctlStatus, Reminder, ReminderList, ReminderListResult, ServerHealth,
+    BatchActionResult, BatchProcessResult, BreakerStatus, CacheStats, CapabilityReport,
+    DeleteResult, DueDate, ListDeleteResult, ListsResult, OccurrencesResult, Reminder,
+    ReminderList, ReminderListResult, SearchResult, ServerHealth, ToolError,
 };
+use crate::paging::{self, PageRequest, SortBy, SortOrder};
+use crate::priority::{Priority, PriorityInput};
+use crate::projection::{OutputFormat, Projection};
+use crate::query::{Query, QueryCondition, QueryContext};
+use crate::recurrence::{Recurrence, RecurrenceInput};
 use crate::remindctl::RemindctlRunner;
+use crate::resilience::{CircuitBreaker, RetryPolicy};
 use crate::resolve::{
-    resolve_list_name, resolve_reminder_ids, resolve_reminder_ids_lenient, validate_text_input,
+    ListRefs, ListScope, resolve_list_name, resolve_reminder_ids, resolve_reminder_ids_lenient,
+    validate_text_input,
 };
+use crate::schema::SchemaDrift;
+use crate::search;
 
 pub struct RuntimeState {
     pub config: Config,
-    pub runner: RemindctlRunner,
+    pub backend: Arc<dyn ReminderBackend>,
+    pub cache: Arc<SnapshotCache>,
+    pub breaker: Arc<CircuitBreaker>,
+    pub capabilities: Arc<Capabilities>,
+    pub schema_drift: Arc<SchemaDrift>,
     recent_reminder_id: Mutex<Option<String>>,
+    mutations: tokio::sync::Mutex<()>,
 }
 
 impl RuntimeState {
-    pub fn new(config: Config) -> Result<Self, AppError> {
-        let runner = RemindctlRunner::new(
-            config.remindctl_bin.clone(),
-            config.read_timeout,
-            config.write_timeout,
-        );
+    /// Builds the configured backend. For `remindctl`, probes the binary's version and
+    /// flags first so tools can reject unsupported options up front.
+    pub async fn new(config: Config) -> Result<Self, AppError> {
+        let breaker = Arc::new(CircuitBreaker::new(
+            config.breaker_threshold,
+            config.breaker_cooldown,
+        ));
+        let mut capabilities = Arc::new(Capabilities::unknown());
+        let schema_drift = Arc::new(SchemaDrift::new());
+        let backend: Arc<dyn ReminderBackend> = match config.backend {
+            BackendKind::Remindctl => {
+                let runner = RemindctlRunner::new(
+                    config.remindctl_bin.clone(),
+                    config.read_timeout,
+                    config.write_timeout,
+                )
+                .with_max_concurrent_reads(config.max_concurrent_reads)
+                .with_retry_policy(RetryPolicy {
+                    max_retries: config.max_retries,
+                    base_delay: config.retry_base_delay,
+                })
+                .with_circuit_breaker(Arc::clone(&breaker))
+                .with_schema_drift(Arc::clone(&schema_drift));
+                let runner = match (config.fixture_mode, &config.fixture_dir) {
+                    (FixtureMode::Record, Some(dir)) => {
+                        runner.with_fixtures(Fixtures::Record(FixtureRecorder::new(dir)?))
+                    }
+                    (FixtureMode::Replay, Some(dir)) => {
+                        runner.with_fixtures(Fixtures::Replay(FixtureReplayer::load(dir)?))
+                    }
+                    _ => runner,
+                };
+                capabilities = Arc::new(runner.probe_capabilities().await);
+                log_capabilities(&capabilities);
+                Arc::new(runner.with_capabilities(Arc::clone(&capabilities)))
+            }
+            BackendKind::Memory => match &config.memory_fixture {
+                Some(path) => Arc::new(MemoryBackend::from_fixture_file(path)?),
+                None => Arc::new(MemoryBackend::new()),
+            },
+        };
+
+        let mut state = Self::with_backend(config, backend);
+        state.breaker = breaker;
+        state.capabilities = capabilities;
+        state.schema_drift = schema_drift;
+        Ok(state)
+    }
 
-        Ok(Self {
+    pub fn with_backend(config: Config, backend: Arc<dyn ReminderBackend>) -> Self {
+        let cache = Arc::new(SnapshotCache::new(config.cache_ttl));
+        let breaker = Arc::new(CircuitBreaker::new(
+            config.breaker_threshold,
+            config.breaker_cooldown,
+        ));
+        Self {
             config,
-            runner,
+            backend: Arc::new(CachingBackend::new(backend, Arc::clone(&cache))),
+            cache,
+            breaker,
+            capabilities: Arc::new(Capabilities::unknown()),
+            schema_drift: Arc::new(SchemaDrift::new()),
             recent_reminder_id: Mutex::new(None),
-        })
+            mutations: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Held by every mutating tool across resolve-then-write, so concurrent sessions
+    /// cannot act on IDs another session is about to change.
+    pub async fn lock_mutations(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        let started = Instant::now();
+        let guard = self.mutations.lock().await;
+        tracing::debug!(
+            wait_ms = started.elapsed().as_millis() as u64,
+            "acquired mutation lock"
+        );
+        guard
     }
 }
 
@@ -72,17 +167,283 @@ impl AppServer {
     }
 
     async fn fetch_lists(&self) -> Result<Vec<ReminderList>, AppError> {
-        self.state
-            .runner
-            .run_read_json::<Vec<ReminderList>>(vec!["list".to_owned()])
-            .await
+        self.state.backend.lists().await
     }
 
     async fn fetch_all_reminders(&self) -> Result<Vec<Reminder>, AppError> {
-        self.state
-            .runner
-            .run_read_json::<Vec<Reminder>>(vec!["show".to_owned(), "all".to_owned()])
+        self.state.backend.show("all", None).await
+    }
+
+    /// Validates a `due` input, ISO or a phrase, before anything runs.
+    fn parse_due(&self, due: Option<&str>) -> Result<Option<Due>, AppError> {
+        due.map(|due| Due::resolve(due, self.state.config.timezone, Utc::now()))
+            .transpose()
+    }
+
+    /// Validates `alarms` input before anything runs; absolute times resolve like `due`.
+    fn parse_alarms(&self, alarms: Option<&[Value]>) -> Result<Vec<Alarm>, AppError> {
+        alarms
+            .map(|alarms| Alarm::parse_all(alarms, self.state.config.timezone, Utc::now()))
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+
+    /// Validates `recurrence` input; a floating `until` resolves like `due`.
+    fn parse_recurrence(&self, input: Option<&Value>) -> Result<Option<Recurrence>, AppError> {
+        input
+            .filter(|input| !input.is_null())
+            .map(|input| Recurrence::from_input(input, self.state.config.timezone))
+            .transpose()
+    }
+
+    /// Validates the `query` text and `where` conditions against the current lists.
+    fn parse_query(
+        &self,
+        lists: &[ReminderList],
+        text: Option<&str>,
+        conditions: &[QueryCondition],
+    ) -> Result<Query, AppError> {
+        let context = QueryContext {
+            lists,
+            tz: self.state.config.timezone,
+            now: Utc::now(),
+        };
+        let parsed = match text {
+            Some(text) => {
+                validate_text_input(text, "query", 1000)?;
+                Query::parse(text, &context)?
+            }
+            None => Query::default(),
+        };
+        Ok(parsed.and(Query::from_conditions(conditions, &context)?))
+    }
+
+    /// `reminders_list`, shaped by `fields` and `format`.
+    async fn list_reminders(&self, input: ReminderListInput) -> Result<CallToolResult, ToolError> {
+        let projection =
+            Projection::parse(input.fields.as_deref(), input.format).map_err(tool_error)?;
+        let min_priority =
+            parse_priority(input.min_priority.as_ref(), "minPriority").map_err(tool_error)?;
+        let lists = self.fetch_lists().await.map_err(tool_error)?;
+        let list_scope = input
+            .lists
+            .resolve(&lists, input.list_id.as_deref(), input.list_name.as_deref())
+            .map_err(tool_error)?;
+        let query = self
+            .parse_query(
+                &lists,
+                input.query.as_deref(),
+                input.conditions.as_deref().unwrap_or_default(),
+            )
+            .map_err(tool_error)?;
+
+        let raw_filter = input
+            .filter
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or("pending");
+
+        let pending_mode = matches!(
+            raw_filter.to_ascii_lowercase().as_str(),
+            "pending" | "incomplete"
+        );
+        let filter = if pending_mode { "all" } else { raw_filter };
+
+        let mut reminders = self
+            .state
+            .backend
+            .show(filter, list_scope.list_name())
+            .await
+            .map_err(tool_error)?;
+        list_scope.apply(&mut reminders);
+
+        if pending_mode && !input.include_completed.unwrap_or(false) && !query.mentions_completion()
+        {
+            reminders.retain(|reminder| !reminder.is_completed);
+        }
+        let (tz, now) = (self.state.config.timezone, Utc::now());
+        reminders.retain(|reminder| query.matches(reminder, tz, now));
+        if let Some(min_priority) = min_priority {
+            reminders.retain(|reminder| reminder.priority >= min_priority);
+        }
+
+        // JSON values keep object keys sorted, so cursors survive changes to Debug
+        // output or field order.
+        let canonical = |value: serde_json::Result<Value>| {
+            value
+                .map(|value| value.to_string())
+                .map_err(|err| tool_error(AppError::from(err)))
+        };
+        let lists = canonical(serde_json::to_value(&input.lists))?;
+        let conditions = canonical(serde_json::to_value(&input.conditions))?;
+        let scope = paging::fingerprint(&[
+            "reminders_list",
+            raw_filter,
+            list_scope.list_name().unwrap_or_default(),
+            &lists,
+            &input.include_completed.unwrap_or(false).to_string(),
+            min_priority.map(Priority::as_str).unwrap_or_default(),
+            input.query.as_deref().unwrap_or_default(),
+            &conditions,
+        ]);
+        let request = PageRequest {
+            sort_by: input.sort_by,
+            order: input.order,
+            limit: input.limit,
+            cursor: input.cursor,
+            scope,
+        };
+        let sorted =
+            request.sort_by.is_some() || request.limit.is_some() || request.cursor.is_some();
+        let page = paging::paginate(reminders, &request, tz).map_err(tool_error)?;
+
+        let mut result = serde_json::to_value(ReminderListResult {
+            reminders: self.localize(page.reminders),
+            total: sorted.then_some(page.total),
+            next_cursor: page.next_cursor,
+        })
+        .map_err(|err| tool_error(AppError::from(err)))?;
+        let table = projection.table(result["reminders"].as_array().into_iter().flatten());
+        projection.shape_all(&mut result["reminders"]);
+        Ok(shaped_result(result, table))
+    }
+
+    /// `reminders_search`, shaped by `fields` and `format`.
+    async fn search_reminders(
+        &self,
+        input: ReminderSearchInput,
+    ) -> Result<CallToolResult, ToolError> {
+        let projection =
+            Projection::parse(input.fields.as_deref(), input.format).map_err(tool_error)?;
+        validate_text_input(&input.query, "query", 200).map_err(tool_error)?;
+        let limit = input.limit.unwrap_or(20);
+        if !(1..=100).contains(&limit) {
+            return Err(tool_error(AppError::invalid_field(
+                "limit",
+                "limit must be between 1 and 100",
+            )));
+        }
+        search::query_terms(&input.query).map_err(tool_error)?;
+
+        let lists = self.fetch_lists().await.map_err(tool_error)?;
+        let list_scope = input
+            .lists
+            .resolve(&lists, input.list_id.as_deref(), input.list_name.as_deref())
+            .map_err(tool_error)?;
+        let mut reminders = self
+            .state
+            .backend
+            .show("all", list_scope.list_name())
             .await
+            .map_err(tool_error)?;
+        list_scope.apply(&mut reminders);
+        if !input.include_completed.unwrap_or(false) {
+            reminders.retain(|reminder| !reminder.is_completed);
+        }
+
+        let mut results = search::search(reminders, &input.query).map_err(tool_error)?;
+        let total = results.len();
+        results.truncate(limit);
+        for hit in &mut results {
+            hit.reminder = self.localize_one(std::mem::take(&mut hit.reminder));
+        }
+        let mut result = serde_json::to_value(SearchResult { total, results })
+            .map_err(|err| tool_error(AppError::from(err)))?;
+        let hits = result["results"]
+            .as_array_mut()
+            .map_or(&mut [][..], Vec::as_mut_slice);
+        let table = projection.table(hits.iter().map(|hit| &hit["reminder"]));
+        for hit in hits {
+            projection.shape(&mut hit["reminder"]);
+        }
+        Ok(shaped_result(result, table))
+    }
+
+    /// A reminder resource body: a bare array, or `{reminders, total, nextCursor}` when
+    /// paged, shaped by `fields`; `format=table` returns tab-separated text instead.
+    fn reminders_resource(
+        &self,
+        uri: &str,
+        reminders: Vec<Reminder>,
+        params: &ResourceParams,
+    ) -> Result<ReadResourceResult, McpError> {
+        let mut value = match &params.page {
+            Some(request) => {
+                let page = paging::paginate(reminders, request, self.state.config.timezone)
+                    .map_err(|err| McpError::invalid_params(err.to_string(), None))?;
+                serde_json::to_value(ReminderListResult {
+                    reminders: self.localize(page.reminders),
+                    total: Some(page.total),
+                    next_cursor: page.next_cursor,
+                })
+            }
+            None => serde_json::to_value(self.localize(reminders)),
+        }
+        .map_err(to_mcp_error)?;
+        let rows = match params.page {
+            Some(_) => &mut value["reminders"],
+            None => &mut value,
+        };
+        let contents = match params
+            .projection
+            .table(rows.as_array().into_iter().flatten())
+        {
+            Some(table) => ResourceContents::TextResourceContents {
+                uri: uri.to_owned(),
+                mime_type: Some("text/tab-separated-values".to_owned()),
+                text: table,
+                meta: None,
+            },
+            None => {
+                params.projection.shape_all(rows);
+                ResourceContents::text(value.to_string(), uri)
+            }
+        };
+        Ok(ReadResourceResult {
+            contents: vec![contents],
+        })
+    }
+
+    /// Fills in `due` from `dueDate` for reminders leaving the server, and reads a
+    /// floating recurrence `until` or alarm time in the configured timezone.
+    fn localize(&self, mut reminders: Vec<Reminder>) -> Vec<Reminder> {
+        let tz = self.state.config.timezone;
+        for reminder in &mut reminders {
+            reminder.due = reminder
+                .due_date
+                .as_deref()
+                .and_then(|due| dates::due_date(due, tz));
+            reminder.recurrence = reminder
+                .recurrence
+                .take()
+                .map(|recurrence| recurrence.localized(tz));
+            for alarm in reminder.alarms.iter_mut().flatten() {
+                *alarm = alarm.clone().localized(tz);
+            }
+        }
+        reminders
+    }
+
+    fn localize_one(&self, reminder: Reminder) -> Reminder {
+        self.localize(vec![reminder]).remove(0)
+    }
+
+    /// Localizes a written reminder and echoes the `due` input with what it resolved to,
+    /// falling back to the resolved value if the backend did not report a due date.
+    fn echo_due(&self, reminder: Reminder, input: Option<String>, due: Option<Due>) -> Reminder {
+        let mut reminder = self.localize_one(reminder);
+        if let (Some(input), Some(due)) = (input, due) {
+            reminder.due = reminder
+                .due
+                .take()
+                .or_else(|| dates::due_date(&due.remindctl_arg(), self.state.config.timezone))
+                .map(|resolved| DueDate {
+                    input: Some(input),
+                    ..resolved
+                });
+        }
+        reminder
     }
 
     fn infer_best_list_name(
@@ -196,6 +557,98 @@ pub struct ReminderListInput {
     pub list_id: Option<String>,
     #[serde(rename = "listName", default)]
     pub list_name: Option<String>,
+    /// Only reminders at or above this priority.
+    #[serde(rename = "minPriority", default)]
+    pub min_priority: Option<PriorityInput>,
+    #[serde(rename = "sortBy", default)]
+    pub sort_by: Option<SortBy>,
+    /// Defaults to desc for priority and asc for everything else.
+    #[serde(default)]
+    pub order: Option<SortOrder>,
+    /// Page size (1-500). Without it every match is returned.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// `nextCursor` from the previous page of the same request.
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// Query such as `list:Compras priority>=medium due<2026-11-01 !completed text:"leche"`.
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Structured form of `query`; both may be given and are ANDed.
+    #[serde(rename = "where", default)]
+    pub conditions: Option<Vec<QueryCondition>>,
+    #[serde(flatten)]
+    pub lists: ListScopeInput,
+    /// Reminder fields to return, such as ["id", "title", "due"]; id is always kept.
+    #[serde(default)]
+    pub fields: Option<Vec<String>>,
+    /// json (default), compact or table.
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+}
+
+/// Multi-list scoping for the read tools, on top of `listId`/`listName`.
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ListScopeInput {
+    /// Only reminders in these lists; combined with listNames, listId and listName.
+    #[serde(rename = "listIds", default)]
+    pub list_ids: Vec<String>,
+    #[serde(rename = "listNames", default)]
+    pub list_names: Vec<String>,
+    /// Leave out reminders in these lists.
+    #[serde(rename = "excludeListIds", default)]
+    pub exclude_list_ids: Vec<String>,
+    #[serde(rename = "excludeListNames", default)]
+    pub exclude_list_names: Vec<String>,
+}
+
+impl ListScopeInput {
+    fn resolve(
+        &self,
+        lists: &[ReminderList],
+        list_id: Option<&str>,
+        list_name: Option<&str>,
+    ) -> Result<ListScope, AppError> {
+        ListScope::resolve(
+            lists,
+            resolve_list_name(lists, list_id, list_name)?,
+            ListRefs {
+                ids: &self.list_ids,
+                names: &self.list_names,
+                id_field: "listIds",
+                name_field: "listNames",
+            },
+            ListRefs {
+                ids: &self.exclude_list_ids,
+                names: &self.exclude_list_names,
+                id_field: "excludeListIds",
+                name_field: "excludeListNames",
+            },
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReminderSearchInput {
+    /// Words to find in title and notes. Case, accents and small typos are ignored.
+    pub query: String,
+    #[serde(rename = "includeCompleted", default)]
+    pub include_completed: Option<bool>,
+    #[serde(rename = "listId", default)]
+    pub list_id: Option<String>,
+    #[serde(rename = "listName", default)]
+    pub list_name: Option<String>,
+    /// Most results to return (1-100, default 20).
+    #[serde(default)]
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub lists: ListScopeInput,
+    /// Reminder fields to return, such as ["id", "title", "due"]; id is always kept.
+    #[serde(default)]
+    pub fields: Option<Vec<String>>,
+    /// json (default), compact or table.
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -210,7 +663,15 @@ pub struct ReminderAddInput {
     #[serde(default)]
     pub notes: Option<String>,
     #[serde(default)]
-    pub priority: Option<String>,
+    pub priority: Option<PriorityInput>,
+    /// Repeat rule: an RRULE string such as `FREQ=WEEKLY;BYDAY=MO`, or the structured form.
+    #[serde(default)]
+    #[schemars(with = "Option<RecurrenceInput>")]
+    pub recurrence: Option<Value>,
+    /// Alerts: "15 minutes before", "1 hora antes", a date/time, `{minutesBefore}` or `{at}`.
+    #[serde(default)]
+    #[schemars(with = "Option<Vec<AlarmInput>>")]
+    pub alarms: Option<Vec<Value>>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -230,11 +691,40 @@ pub struct ReminderEditInput {
     #[serde(default)]
     pub notes: Option<String>,
     #[serde(default)]
-    pub priority: Option<String>,
+    pub priority: Option<PriorityInput>,
+    /// Repeat rule: an RRULE string such as `FREQ=WEEKLY;BYDAY=MO`, or the structured form.
+    #[serde(default)]
+    #[schemars(with = "Option<RecurrenceInput>")]
+    pub recurrence: Option<Value>,
+    #[serde(rename = "clearRecurrence", default)]
+    pub clear_recurrence: Option<bool>,
+    /// Replaces the reminder's alarms; same forms as `reminder_add`.
+    #[serde(default)]
+    #[schemars(with = "Option<Vec<AlarmInput>>")]
+    pub alarms: Option<Vec<Value>>,
+    #[serde(rename = "clearAlarms", default)]
+    pub clear_alarms: Option<bool>,
     #[serde(default)]
     pub complete: Option<bool>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReminderOccurrencesInput {
+    /// Preview this reminder's rule, starting from its due date.
+    #[serde(rename = "reminderId", default)]
+    pub reminder_id: Option<String>,
+    /// A rule to preview instead of (or in place of) the reminder's own.
+    #[serde(default)]
+    #[schemars(with = "Option<RecurrenceInput>")]
+    pub recurrence: Option<Value>,
+    /// First occurrence, in any form `due` accepts. Defaults to the reminder's due date, or today.
+    #[serde(default)]
+    pub start: Option<String>,
+    /// How many upcoming occurrences to return (1-100, default 5).
+    #[serde(default)]
+    pub count: Option<usize>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ReminderMultiInput {
     #[serde(rename = "reminderIds", default)]
@@ -295,98 +785,104 @@ pub struct ServerConfigResource {
     pub read_timeout_secs: u64,
     #[serde(rename = "writeTimeoutSecs")]
     pub write_timeout_secs: u64,
+    #[serde(rename = "maxConcurrentReads")]
+    pub max_concurrent_reads: usize,
+    #[serde(rename = "maxRetries")]
+    pub max_retries: u32,
+    #[serde(rename = "breakerThreshold")]
+    pub breaker_threshold: u32,
+    #[serde(rename = "breakerCooldownSecs")]
+    pub breaker_cooldown_secs: u64,
+    /// Timezone for due inputs without an offset and for `due.local`.
+    pub timezone: String,
+    #[serde(rename = "snapshotCache")]
+    pub snapshot_cache: CacheStats,
+    pub breaker: BreakerStatus,
+    #[serde(rename = "remindctlCapabilities")]
+    pub remindctl_capabilities: CapabilityReport,
 }
 
 #[tool_router]
 impl AppServer {
     #[tool(
-        description = "Health check. Use this first when troubleshooting connectivity or permissions. Returns server auth mode and remindctl authorization state."
+        description = "Health check. Use this first when troubleshooting connectivity or permissions. Returns server auth mode, remindctl authorization state, snapshot cache stats, the remindctl circuit breaker state, and the detected remindctl version and unsupported flags. While the breaker is open, ok is false and writes should stay queued."
     )]
-    async fn server_health(&self) -> Result<Json<ServerHealth>, String> {
-        let status = self
-            .state
-            .runner
-            .run_read_json::<RemindctlStatus>(vec!["status".to_owned()])
-            .await
-            .map_err(tool_error)?;
+    async fn server_health(&self) -> Result<Json<ServerHealth>, ToolError> {
+        let (ok, remindctl_authorized, remindctl_status) = match self.state.backend.status().await {
+            Ok(status) => (true, status.authorized, status.status),
+            Err(AppError::CircuitOpen { .. }) => (false, false, "unavailable".to_owned()),
+            Err(err) => return Err(tool_error(err)),
+        };
 
         Ok(Json(ServerHealth {
-            ok: true,
+            ok,
             auth_required: self.state.config.auth_required,
-            remindctl_authorized: status.authorized,
-            remindctl_status: status.status,
+            remindctl_authorized,
+            remindctl_status,
+            cache: self.state.cache.stats(),
+            breaker: self.state.breaker.status(),
+            capabilities: self.state.capabilities.report(),
         }))
     }
 
     #[tool(
         description = "List all Apple Reminders lists with IDs and counts. Use this before write operations when you need a stable listId."
     )]
-    async fn lists_list(&self) -> Result<Json<ListsResult>, String> {
+    async fn lists_list(&self) -> Result<Json<ListsResult>, ToolError> {
         let lists = self.fetch_lists().await.map_err(tool_error)?;
         Ok(Json(ListsResult { lists }))
     }
 
     #[tool(
-        description = "Primary read tool for reminders. If filter is omitted, return pending reminders only. Supported filter values: pending, incomplete, today, tomorrow, week, overdue, upcoming, completed, all, or a date string in ISO 8601/RFC3339 format (for example 2026-03-01 or 2026-03-01T14:30:00Z). Prefer this tool over manual filtering."
+        description = "Primary read tool for reminders. If filter is omitted, return pending reminders only. Supported filter values: pending, incomplete, today, tomorrow, week, overdue, upcoming, completed, all, or a date string in ISO 8601/RFC3339 format (for example 2026-03-01 or 2026-03-01T14:30:00Z). minPriority keeps reminders at or above a priority. sortBy (priority, due, title, list, created, modified) orders the result, with order asc|desc (priority defaults to desc); reminders missing the sort value come last and ID breaks ties. limit (1-500) pages the result: pass nextCursor back as cursor with otherwise identical arguments for the next page, and total counts every match; paging without sortBy orders by due. A cursor from a different filter, query or sort fails with invalid_input on field cursor. listIds/listNames take several lists and excludeListIds/excludeListNames leave lists out; all are checked up front and unknown ones fail with list_not_found naming each. Results from several lists come grouped in the order the lists were given. query narrows the result with space-separated terms that must all hold: list:NAME, text:WORD (or a bare word; quote values with spaces), has:notes|due|url|recurrence|alarms|priority, priority>=medium, due<2026-11-01 (ops = != < <= > >=, dates or phrases), and flags completed, flagged, overdue, recurring (also is:flag); prefix ! or - to negate. where is the same as a list of {field, op, value, not} conditions. Completed reminders stay hidden unless includeCompleted or the query mentions completed. Malformed queries fail with invalid_input naming the token and column (field query, or where[i]). Each reminder with a due date also carries due: {original, utc, local, date, allDay, timezone}. fields picks reminder fields to return (id is always kept). format is json (default), compact (short keys i id, t title, l listName, li listID, c isCompleted, p priority, d due local time or date, n notes trimmed to 80 chars, u url, f flagged, r recurrence RRULE, a alarms; empty, false and none values dropped) or table (tab-separated text content alongside the json structured content). Prefer this tool over manual filtering.",
+        output_schema = shaped_schema::<ReminderListResult>()
     )]
     async fn reminders_list(
         &self,
         Parameters(input): Parameters<ReminderListInput>,
-    ) -> Result<Json<ReminderListResult>, String> {
-        let lists = self.fetch_lists().await.map_err(tool_error)?;
-        let list_name =
-            resolve_list_name(&lists, input.list_id.as_deref(), input.list_name.as_deref())
-                .map_err(tool_error)?;
-
-        let raw_filter = input
-            .filter
-            .as_deref()
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .unwrap_or("pending");
-
-        let mut args = vec!["show".to_owned()];
-        let pending_mode = matches!(
-            raw_filter.to_ascii_lowercase().as_str(),
-            "pending" | "incomplete"
-        );
-        if pending_mode {
-            args.push("all".to_owned());
-        } else {
-            args.push(raw_filter.to_owned());
-        }
-
-        if let Some(name) = list_name {
-            args.push("--list".to_owned());
-            args.push(name);
-        }
-
-        let mut reminders = self
-            .state
-            .runner
-            .run_read_json::<Vec<Reminder>>(args)
+    ) -> Result<CallToolResult, McpError> {
+        Ok(self
+            .list_reminders(input)
             .await
-            .map_err(tool_error)?;
-
-        if pending_mode && !input.include_completed.unwrap_or(false) {
-            reminders.retain(|reminder| !reminder.is_completed);
-        }
+            .unwrap_or_else(tool_failure))
+    }
 
-        Ok(Json(ReminderListResult { reminders }))
+    #[tool(
+        description = "Search reminder titles and notes instead of listing everything. Matching ignores case and accents (cafe finds Café) and tolerates small typos (morgage finds Mortgage); every query word must match. Results are ranked best first (title matches outweigh notes) with highlights giving the field, character range and kind of each match. Pending reminders only unless includeCompleted is true; listId or listName scope it to one list, and listIds/listNames/excludeListIds/excludeListNames to several as in reminders_list. total counts all matches before limit (default 20, max 100). fields picks reminder fields to return (id is always kept). format is json (default), compact (short keys i id, t title, l listName, li listID, c isCompleted, p priority, d due local time or date, n notes trimmed to 80 chars, u url, f flagged, r recurrence RRULE, a alarms; empty, false and none values dropped) or table (tab-separated text content alongside the json structured content).",
+        output_schema = shaped_schema::<SearchResult>()
+    )]
+    async fn reminders_search(
+        &self,
+        Parameters(input): Parameters<ReminderSearchInput>,
+    ) -> Result<CallToolResult, McpError> {
+        Ok(self
+            .search_reminders(input)
+            .await
+            .unwrap_or_else(tool_failure))
     }
 
     #[tool(
-        description = "Create a reminder from natural input. Use listId or listName when you need strict placement. For due dates, pass due as ISO 8601/RFC3339 (for example 2026-03-01 or 2026-03-01T14:30:00Z). If list is omitted, auto-route to the best matching existing list using title+notes semantic overlap; if no strong match exists, fall back to Reminders/Inbox/Todo/Tareas, then first available list."
+        description = "Create a reminder from natural input. Use listId or listName when you need strict placement. priority is none, low, medium or high; !, !!, !!!, urgent, alta, media, baja and EventKit numbers 0-9 are also accepted. For due dates, pass due as YYYY-MM-DD for an all-day reminder, RFC3339 with an offset (for example 2026-03-01T14:30:00Z), YYYY-MM-DDTHH:MM in the server timezone, or an English/Spanish phrase such as \"tomorrow at 5pm\", \"in 2 hours\", \"mañana a las 9\" or \"el lunes\"; anything else is rejected with invalid_input before remindctl runs. The result's due echoes the input with its resolved utc and local time; confirm with those. recurrence makes it repeat: an RRULE such as FREQ=MONTHLY;BYMONTHDAY=1 or {frequency, interval, byDay, byMonthDay, until, count}; preview it with reminder_occurrences. alarms is a list of alerts: offsets before the due time (\"15 minutes before\", \"1 hora antes\", \"1h\", {minutesBefore: 60}) which need a due, or absolute times (\"2026-03-01T09:00\", \"mañana a las 8\", {at}); output reports them as {type: relative, minutesBefore} or {type: absolute, at}. If list is omitted, auto-route to the best matching existing list using title+notes semantic overlap; if no strong match exists, fall back to Reminders/Inbox/Todo/Tareas, then first available list."
     )]
     async fn reminder_add(
         &self,
         Parameters(input): Parameters<ReminderAddInput>,
-    ) -> Result<Json<Reminder>, String> {
+    ) -> Result<Json<Reminder>, ToolError> {
         validate_text_input(&input.title, "title", 300).map_err(tool_error)?;
         if let Some(notes) = &input.notes {
             validate_text_input(notes, "notes", 4000).map_err(tool_error)?;
         }
+        let due = self.parse_due(input.due.as_deref()).map_err(tool_error)?;
+        let priority = parse_priority(input.priority.as_ref(), "priority").map_err(tool_error)?;
+        let recurrence = self
+            .parse_recurrence(input.recurrence.as_ref())
+            .map_err(tool_error)?;
+        let alarms = self
+            .parse_alarms(input.alarms.as_deref())
+            .map_err(tool_error)?;
+        require_due_for_relative(&alarms, due.is_some()).map_err(tool_error)?;
 
+        let _mutation = self.state.lock_mutations().await;
         let lists = self.fetch_lists().await.map_err(tool_error)?;
         let list_name =
             resolve_list_name(&lists, input.list_id.as_deref(), input.list_name.as_deref())
@@ -395,28 +891,18 @@ impl AppServer {
                     Self::infer_best_list_name(&lists, &input.title, input.notes.as_deref())
                 });
 
-        let mut args = vec!["add".to_owned(), "--title".to_owned(), input.title];
-        if let Some(name) = list_name {
-            args.push("--list".to_owned());
-            args.push(name);
-        }
-        if let Some(due) = input.due {
-            args.push("--due".to_owned());
-            args.push(due);
-        }
-        if let Some(notes) = input.notes {
-            args.push("--notes".to_owned());
-            args.push(notes);
-        }
-        if let Some(priority) = input.priority {
-            args.push("--priority".to_owned());
-            args.push(priority);
-        }
-
         let reminder = self
             .state
-            .runner
-            .run_write_json::<Reminder>(args)
+            .backend
+            .add(NewReminder {
+                title: input.title,
+                list_name,
+                due: due.map(|due| due.remindctl_arg()),
+                notes: input.notes,
+                priority,
+                recurrence,
+                alarms,
+            })
             .await
             .map_err(tool_error)?;
 
@@ -424,68 +910,139 @@ impl AppServer {
             *recent = Some(reminder.id.clone());
         }
 
-        Ok(Json(reminder))
+        Ok(Json(self.echo_due(reminder, input.due, due)))
     }
 
     #[tool(
-        description = "Update an existing reminder by ID or unique ID prefix. Supports title, due date, notes, priority, completion state, and list move. For due, use ISO 8601/RFC3339 (for example 2026-03-01 or 2026-03-01T14:30:00Z). Never uses numeric index semantics."
+        description = "Update an existing reminder by ID or unique ID prefix. Supports title, due date, notes, priority (same spellings as reminder_add), completion state, and list move. For due, use YYYY-MM-DD (all-day), RFC3339 with an offset (for example 2026-03-01T14:30:00Z), YYYY-MM-DDTHH:MM in the server timezone, or a phrase like \"next friday\" or \"mañana a las 9\"; due.input in the result echoes it. recurrence takes the same rules as reminder_add and clearRecurrence stops repeating. alarms (same forms as reminder_add) replaces the existing alarms and clearAlarms removes them all. Never uses numeric index semantics."
     )]
     async fn reminder_edit(
         &self,
         Parameters(input): Parameters<ReminderEditInput>,
-    ) -> Result<Json<Reminder>, String> {
+    ) -> Result<Json<Reminder>, ToolError> {
+        let due = self.parse_due(input.due.as_deref()).map_err(tool_error)?;
+        let priority = parse_priority(input.priority.as_ref(), "priority").map_err(tool_error)?;
+        let recurrence = self
+            .parse_recurrence(input.recurrence.as_ref())
+            .map_err(tool_error)?;
+        let alarms = self
+            .parse_alarms(input.alarms.as_deref())
+            .map_err(tool_error)?;
+        let clear_due = input.clear_due.unwrap_or(false);
+
+        let _mutation = self.state.lock_mutations().await;
         let all_reminders = self.fetch_all_reminders().await.map_err(tool_error)?;
         let resolved_id = resolve_reminder_ids(&all_reminders, &[input.reminder_id])
             .map_err(tool_error)?
             .remove(0);
+        let keeps_due = !clear_due
+            && all_reminders
+                .iter()
+                .any(|reminder| reminder.id == resolved_id && reminder.due_date.is_some());
+        require_due_for_relative(&alarms, due.is_some() || keeps_due).map_err(tool_error)?;
 
         let lists = self.fetch_lists().await.map_err(tool_error)?;
         let list_name =
             resolve_list_name(&lists, input.list_id.as_deref(), input.list_name.as_deref())
                 .map_err(tool_error)?;
 
-        let mut args = vec!["edit".to_owned(), resolved_id];
-        if let Some(title) = input.title {
-            validate_text_input(&title, "title", 300).map_err(tool_error)?;
-            args.push("--title".to_owned());
-            args.push(title);
-        }
-        if let Some(name) = list_name {
-            args.push("--list".to_owned());
-            args.push(name);
-        }
-        if let Some(due) = input.due {
-            args.push("--due".to_owned());
-            args.push(due);
-        }
-        if input.clear_due.unwrap_or(false) {
-            args.push("--clear-due".to_owned());
-        }
-        if let Some(notes) = input.notes {
-            validate_text_input(&notes, "notes", 4000).map_err(tool_error)?;
-            args.push("--notes".to_owned());
-            args.push(notes);
+        if let Some(title) = &input.title {
+            validate_text_input(title, "title", 300).map_err(tool_error)?;
         }
-        if let Some(priority) = input.priority {
-            args.push("--priority".to_owned());
-            args.push(priority);
-        }
-        if let Some(complete) = input.complete {
-            args.push(if complete {
-                "--complete".to_owned()
-            } else {
-                "--incomplete".to_owned()
-            });
+        if let Some(notes) = &input.notes {
+            validate_text_input(notes, "notes", 4000).map_err(tool_error)?;
         }
 
         let reminder = self
             .state
-            .runner
-            .run_write_json::<Reminder>(args)
+            .backend
+            .edit(
+                &resolved_id,
+                ReminderUpdate {
+                    title: input.title,
+                    list_name,
+                    due: due.map(|due| due.remindctl_arg()),
+                    clear_due,
+                    notes: input.notes,
+                    priority,
+                    recurrence,
+                    clear_recurrence: input.clear_recurrence.unwrap_or(false),
+                    clear_alarms: input.clear_alarms.unwrap_or(false) || input.alarms.is_some(),
+                    alarms,
+                    complete: input.complete,
+                },
+            )
             .await
             .map_err(tool_error)?;
 
-        Ok(Json(reminder))
+        Ok(Json(self.echo_due(reminder, input.due, due)))
+    }
+
+    #[tool(
+        description = "Preview when a repeating reminder will come due. Pass reminderId to expand that reminder's rule from its due date, or recurrence (RRULE string or structured form) with an optional start to try a rule before saving it. Returns the next count occurrences (default 5, max 100) that are not yet over, each with utc, local and date in the server timezone. Read-only."
+    )]
+    async fn reminder_occurrences(
+        &self,
+        Parameters(input): Parameters<ReminderOccurrencesInput>,
+    ) -> Result<Json<OccurrencesResult>, ToolError> {
+        let tz = self.state.config.timezone;
+        let now = Utc::now();
+        let count = input.count.unwrap_or(5);
+        if !(1..=100).contains(&count) {
+            return Err(tool_error(AppError::invalid_field(
+                "count",
+                "count must be between 1 and 100",
+            )));
+        }
+        let mut recurrence = self
+            .parse_recurrence(input.recurrence.as_ref())
+            .map_err(tool_error)?;
+        let mut start = input
+            .start
+            .as_deref()
+            .map(|start| {
+                Due::resolve(start, tz, now).map_err(|_| {
+                    AppError::invalid_field("start", format!("'{start}' is not a date"))
+                })
+            })
+            .transpose()
+            .map_err(tool_error)?;
+
+        if let Some(reminder_id) = input.reminder_id {
+            let all_reminders = self.fetch_all_reminders().await.map_err(tool_error)?;
+            let resolved_id = resolve_reminder_ids(&all_reminders, &[reminder_id])
+                .map_err(tool_error)?
+                .remove(0);
+            let reminder = all_reminders
+                .into_iter()
+                .find(|reminder| reminder.id == resolved_id)
+                .ok_or_else(|| tool_error(AppError::ReminderNotFound(resolved_id.clone())))?;
+            recurrence = recurrence.or(reminder.recurrence);
+            start = start.or_else(|| {
+                reminder
+                    .due_date
+                    .as_deref()
+                    .and_then(|due| Due::parse(due, tz).ok())
+            });
+        }
+        let recurrence = recurrence.ok_or_else(|| {
+            tool_error(AppError::invalid_field(
+                "recurrence",
+                "pass recurrence, or a reminderId whose reminder repeats",
+            ))
+        })?;
+        let start = start.unwrap_or_else(|| Due::AllDay(tz.to_local(now).date_naive()));
+
+        let occurrences = recurrence
+            .expand(start, tz, Some(now), count)
+            .iter()
+            .filter_map(|due| dates::due_date(&due.remindctl_arg(), tz))
+            .collect();
+        Ok(Json(OccurrencesResult {
+            rrule: recurrence.to_rrule(),
+            recurrence,
+            occurrences,
+        }))
     }
 
     #[tool(
@@ -494,7 +1051,7 @@ impl AppServer {
     async fn reminder_complete(
         &self,
         Parameters(input): Parameters<ReminderMultiInput>,
-    ) -> Result<Json<ReminderListResult>, String> {
+    ) -> Result<Json<ReminderListResult>, ToolError> {
         let mut raw_ids = input.reminder_ids;
         if let Some(reminder_id) = input.reminder_id {
             raw_ids.push(reminder_id);
@@ -505,23 +1062,21 @@ impl AppServer {
             )));
         }
 
+        let _mutation = self.state.lock_mutations().await;
         let all_reminders = self.fetch_all_reminders().await.map_err(tool_error)?;
         let resolved_ids = resolve_reminder_ids(&all_reminders, &raw_ids).map_err(tool_error)?;
 
-        let mut args = vec!["complete".to_owned()];
-        args.extend(resolved_ids);
-        if input.dry_run.unwrap_or(false) {
-            args.push("--dry-run".to_owned());
-        }
-
         let reminders = self
             .state
-            .runner
-            .run_write_json::<Vec<Reminder>>(args)
+            .backend
+            .complete(&resolved_ids, input.dry_run.unwrap_or(false))
             .await
             .map_err(tool_error)?;
 
-        Ok(Json(ReminderListResult { reminders }))
+        Ok(Json(ReminderListResult {
+            reminders: self.localize(reminders),
+            ..ReminderListResult::default()
+        }))
     }
 
     #[tool(
@@ -530,7 +1085,7 @@ impl AppServer {
     async fn reminder_delete(
         &self,
         Parameters(input): Parameters<ReminderMultiInput>,
-    ) -> Result<Json<DeleteResult>, String> {
+    ) -> Result<Json<DeleteResult>, ToolError> {
         let mut raw_ids = input.reminder_ids;
         if let Some(reminder_id) = input.reminder_id {
             raw_ids.push(reminder_id);
@@ -551,6 +1106,7 @@ impl AppServer {
             )));
         }
 
+        let _mutation = self.state.lock_mutations().await;
         let all_reminders = self.fetch_all_reminders().await.map_err(tool_error)?;
         let resolution =
             resolve_reminder_ids_lenient(&all_reminders, &raw_ids).map_err(tool_error)?;
@@ -572,24 +1128,16 @@ impl AppServer {
             )));
         }
 
-        let mut args = vec!["delete".to_owned()];
-        args.extend(resolution.resolved_ids.clone());
-        if input.dry_run.unwrap_or(false) {
-            args.push("--dry-run".to_owned());
-        } else {
-            args.push("--force".to_owned());
-        }
-
         let deleted_reminders = self
             .state
-            .runner
-            .run_write_json::<Vec<Reminder>>(args)
+            .backend
+            .delete(&resolution.resolved_ids, input.dry_run.unwrap_or(false))
             .await
             .map_err(tool_error)?;
 
         Ok(Json(DeleteResult {
             deleted_ids: resolution.resolved_ids,
-            deleted_reminders,
+            deleted_reminders: self.localize(deleted_reminders),
             already_absent_refs: resolution.missing_refs,
             used_recent_reference,
             message: "deletion applied; no extra verification required".to_owned(),
@@ -597,12 +1145,12 @@ impl AppServer {
     }
 
     #[tool(
-        description = "Process multiple queued reminder/list mutations in one call. Accepts actions with {id, op, args}. Supported ops: reminder_add, reminder_edit, reminder_complete, reminder_delete, list_create, list_rename, list_delete. Any due/datetime fields inside args must use ISO 8601/RFC3339 (for example 2026-03-01 or 2026-03-01T14:30:00Z). Returns per-action success/error so queue processors can update state without extra verification calls."
+        description = "Process multiple queued reminder/list mutations in one call. Accepts actions with {id, op, args}. Supported ops: reminder_add, reminder_edit, reminder_complete, reminder_delete, list_create, list_rename, list_delete. due inside args accepts the same ISO dates and phrases as reminder_add; prefer the absolute ISO form for queued actions, since phrases resolve when the queue is processed. Returns per-action success/error so queue processors can update state without extra verification calls."
     )]
     async fn process_pending_actions(
         &self,
         Parameters(input): Parameters<BatchProcessInput>,
-    ) -> Result<Json<BatchProcessResult>, String> {
+    ) -> Result<Json<BatchProcessResult>, ToolError> {
         let mut results = Vec::with_capacity(input.actions.len());
         let stop_on_error = input.stop_on_error.unwrap_or(false);
 
@@ -614,13 +1162,21 @@ impl AppServer {
                     op,
                     ok: true,
                     error: None,
+                    code: None,
+                    retryable: None,
+                    field: None,
+                    candidates: Vec::new(),
                     data: Some(value),
                 },
                 Err(error) => BatchActionResult {
                     id: action.id,
                     op,
                     ok: false,
-                    error: Some(error),
+                    error: Some(error.message),
+                    code: Some(error.code),
+                    retryable: Some(error.retryable),
+                    field: error.field,
+                    candidates: error.candidates,
                     data: None,
                 },
             };
@@ -644,51 +1200,74 @@ impl AppServer {
         }))
     }
 
-    async fn execute_batch_action(&self, op: &str, args: Value) -> Result<Value, String> {
+    async fn execute_batch_action(&self, op: &str, args: Value) -> Result<Value, ToolError> {
         match op {
             "reminder_add" => {
-                let input = serde_json::from_value::<ReminderAddInput>(args)
-                    .map_err(|err| format!("invalid reminder_add args: {err}"))?;
+                let input = serde_json::from_value::<ReminderAddInput>(args).map_err(|err| {
+                    tool_error(AppError::invalid_input(format!(
+                        "invalid reminder_add args: {err}"
+                    )))
+                })?;
                 let result = self.reminder_add(Parameters(input)).await?;
-                serde_json::to_value(result.0).map_err(|err| err.to_string())
+                serde_json::to_value(result.0).map_err(|err| tool_error(err.into()))
             }
             "reminder_edit" => {
-                let input = serde_json::from_value::<ReminderEditInput>(args)
-                    .map_err(|err| format!("invalid reminder_edit args: {err}"))?;
+                let input = serde_json::from_value::<ReminderEditInput>(args).map_err(|err| {
+                    tool_error(AppError::invalid_input(format!(
+                        "invalid reminder_edit args: {err}"
+                    )))
+                })?;
                 let result = self.reminder_edit(Parameters(input)).await?;
-                serde_json::to_value(result.0).map_err(|err| err.to_string())
+                serde_json::to_value(result.0).map_err(|err| tool_error(err.into()))
             }
             "reminder_complete" => {
-                let input = serde_json::from_value::<ReminderMultiInput>(args)
-                    .map_err(|err| format!("invalid reminder_complete args: {err}"))?;
+                let input = serde_json::from_value::<ReminderMultiInput>(args).map_err(|err| {
+                    tool_error(AppError::invalid_input(format!(
+                        "invalid reminder_complete args: {err}"
+                    )))
+                })?;
                 let result = self.reminder_complete(Parameters(input)).await?;
-                serde_json::to_value(result.0).map_err(|err| err.to_string())
+                serde_json::to_value(result.0).map_err(|err| tool_error(err.into()))
             }
             "reminder_delete" => {
-                let input = serde_json::from_value::<ReminderMultiInput>(args)
-                    .map_err(|err| format!("invalid reminder_delete args: {err}"))?;
+                let input = serde_json::from_value::<ReminderMultiInput>(args).map_err(|err| {
+                    tool_error(AppError::invalid_input(format!(
+                        "invalid reminder_delete args: {err}"
+                    )))
+                })?;
                 let result = self.reminder_delete(Parameters(input)).await?;
-                serde_json::to_value(result.0).map_err(|err| err.to_string())
+                serde_json::to_value(result.0).map_err(|err| tool_error(err.into()))
             }
             "list_create" => {
-                let input = serde_json::from_value::<ListCreateInput>(args)
-                    .map_err(|err| format!("invalid list_create args: {err}"))?;
+                let input = serde_json::from_value::<ListCreateInput>(args).map_err(|err| {
+                    tool_error(AppError::invalid_input(format!(
+                        "invalid list_create args: {err}"
+                    )))
+                })?;
                 let result = self.list_create(Parameters(input)).await?;
-                serde_json::to_value(result.0).map_err(|err| err.to_string())
+                serde_json::to_value(result.0).map_err(|err| tool_error(err.into()))
             }
             "list_rename" => {
-                let input = serde_json::from_value::<ListRenameInput>(args)
-                    .map_err(|err| format!("invalid list_rename args: {err}"))?;
+                let input = serde_json::from_value::<ListRenameInput>(args).map_err(|err| {
+                    tool_error(AppError::invalid_input(format!(
+                        "invalid list_rename args: {err}"
+                    )))
+                })?;
                 let result = self.list_rename(Parameters(input)).await?;
-                serde_json::to_value(result.0).map_err(|err| err.to_string())
+                serde_json::to_value(result.0).map_err(|err| tool_error(err.into()))
             }
             "list_delete" => {
-                let input = serde_json::from_value::<ListDeleteInput>(args)
-                    .map_err(|err| format!("invalid list_delete args: {err}"))?;
+                let input = serde_json::from_value::<ListDeleteInput>(args).map_err(|err| {
+                    tool_error(AppError::invalid_input(format!(
+                        "invalid list_delete args: {err}"
+                    )))
+                })?;
                 let result = self.list_delete(Parameters(input)).await?;
-                serde_json::to_value(result.0).map_err(|err| err.to_string())
+                serde_json::to_value(result.0).map_err(|err| tool_error(err.into()))
             }
-            _ => Err(format!("unsupported op '{op}'")),
+            _ => Err(tool_error(AppError::invalid_input(format!(
+                "unsupported op '{op}'"
+            )))),
         }
     }
 
@@ -698,16 +1277,13 @@ impl AppServer {
     async fn list_create(
         &self,
         Parameters(input): Parameters<ListCreateInput>,
-    ) -> Result<Json<ReminderList>, String> {
+    ) -> Result<Json<ReminderList>, ToolError> {
         validate_text_input(&input.name, "name", 120).map_err(tool_error)?;
 
+        let _mutation = self.state.lock_mutations().await;
         self.state
-            .runner
-            .run_write_no_output(vec![
-                "list".to_owned(),
-                input.name.clone(),
-                "--create".to_owned(),
-            ])
+            .backend
+            .list_create(&input.name)
             .await
             .map_err(tool_error)?;
 
@@ -730,9 +1306,10 @@ impl AppServer {
     async fn list_rename(
         &self,
         Parameters(input): Parameters<ListRenameInput>,
-    ) -> Result<Json<ReminderList>, String> {
-        validate_text_input(&input.new_name, "new_name", 120).map_err(tool_error)?;
+    ) -> Result<Json<ReminderList>, ToolError> {
+        validate_text_input(&input.new_name, "newName", 120).map_err(tool_error)?;
 
+        let _mutation = self.state.lock_mutations().await;
         let lists = self.fetch_lists().await.map_err(tool_error)?;
         let source_name =
             resolve_list_name(&lists, input.list_id.as_deref(), input.list_name.as_deref())
@@ -742,13 +1319,8 @@ impl AppServer {
                 })?;
 
         self.state
-            .runner
-            .run_write_no_output(vec![
-                "list".to_owned(),
-                source_name,
-                "--rename".to_owned(),
-                input.new_name.clone(),
-            ])
+            .backend
+            .list_rename(&source_name, &input.new_name)
             .await
             .map_err(tool_error)?;
 
@@ -771,7 +1343,8 @@ impl AppServer {
     async fn list_delete(
         &self,
         Parameters(input): Parameters<ListDeleteInput>,
-    ) -> Result<Json<ListDeleteResult>, String> {
+    ) -> Result<Json<ListDeleteResult>, ToolError> {
+        let _mutation = self.state.lock_mutations().await;
         let lists = self.fetch_lists().await.map_err(tool_error)?;
         let source_name =
             resolve_list_name(&lists, input.list_id.as_deref(), input.list_name.as_deref())
@@ -781,13 +1354,8 @@ impl AppServer {
                 })?;
 
         self.state
-            .runner
-            .run_write_no_output(vec![
-                "list".to_owned(),
-                source_name,
-                "--delete".to_owned(),
-                "--force".to_owned(),
-            ])
+            .backend
+            .list_delete(&source_name)
             .await
             .map_err(tool_error)?;
 
@@ -854,7 +1422,21 @@ impl ServerHandler for AppServer {
                     name: "server_config".to_owned(),
                     title: Some("Server Runtime Config".to_owned()),
                     description: Some(
-                        "Effective non-secret runtime config: bind address, auth mode, and timeouts."
+                        "Effective non-secret runtime config: bind address, auth mode, timeouts, and snapshot cache stats."
+                            .to_owned(),
+                    ),
+                    mime_type: Some("application/json".to_owned()),
+                    size: None,
+                    icons: None,
+                    meta: None,
+                }
+                .no_annotation(),
+                rmcp::model::RawResource {
+                    uri: "remindctl://diagnostics/schema".to_owned(),
+                    name: "schema_diagnostics".to_owned(),
+                    title: Some("Remindctl Schema Drift".to_owned()),
+                    description: Some(
+                        "Unknown and missing keys seen in remindctl JSON output, per model, with counts. Non-empty unknownKeys or missingRequiredKeys mean remindctl's output format changed."
                             .to_owned(),
                     ),
                     mime_type: Some("application/json".to_owned()),
@@ -882,7 +1464,7 @@ impl ServerHandler for AppServer {
                         name: "reminders_filter".to_owned(),
                         title: Some("Reminders by Filter".to_owned()),
                         description: Some(
-                            "Read reminders by filter. Supported values: pending, incomplete, today, tomorrow, week, overdue, upcoming, completed, all, or a date string."
+                            "Read reminders by filter. Supported values: pending, incomplete, today, tomorrow, week, overdue, upcoming, completed, all, or a date string. Append ?sortBy=&order=&limit=&cursor= to sort and page; the result is then {reminders, total, nextCursor}. fields=id,title,due and format=compact|table shape it as in reminders_list."
                                 .to_owned(),
                         ),
                         mime_type: Some("application/json".to_owned()),
@@ -896,7 +1478,8 @@ impl ServerHandler for AppServer {
                         name: "list_reminders".to_owned(),
                         title: Some("Reminders by List ID".to_owned()),
                         description: Some(
-                            "Read all reminders in a list identified by list_id.".to_owned(),
+                            "Read all reminders in a list identified by list_id. Takes the same ?sortBy=&order=&limit=&cursor=&fields=&format= options as remindctl://reminders/{filter}."
+                                .to_owned(),
                         ),
                         mime_type: Some("application/json".to_owned()),
                         icons: None,
@@ -909,7 +1492,7 @@ impl ServerHandler for AppServer {
                         name: "list_name_reminders".to_owned(),
                         title: Some("Reminders by List Name".to_owned()),
                         description: Some(
-                            "Read all reminders in a list identified by list_name. Prefer list_id when available."
+                            "Read all reminders in a list identified by list_name. Prefer list_id when available. Takes the same options as remindctl://reminders/{filter}."
                                 .to_owned(),
                         ),
                         mime_type: Some("application/json".to_owned()),
@@ -931,12 +1514,7 @@ impl ServerHandler for AppServer {
         let uri = request.uri;
 
         if uri.as_str() == "remindctl://status" {
-            let status = self
-                .state
-                .runner
-                .run_read_json::<RemindctlStatus>(vec!["status".to_owned()])
-                .await
-                .map_err(to_mcp_error)?;
+            let status = self.state.backend.status().await.map_err(to_mcp_error)?;
             let text = serde_json::to_string(&status).map_err(to_mcp_error)?;
             return Ok(ReadResourceResult {
                 contents: vec![ResourceContents::text(text, uri)],
@@ -957,6 +1535,14 @@ impl ServerHandler for AppServer {
                 bind_addr: self.state.config.bind_addr.to_string(),
                 read_timeout_secs: self.state.config.read_timeout.as_secs(),
                 write_timeout_secs: self.state.config.write_timeout.as_secs(),
+                max_concurrent_reads: self.state.config.max_concurrent_reads,
+                max_retries: self.state.config.max_retries,
+                breaker_threshold: self.state.config.breaker_threshold,
+                breaker_cooldown_secs: self.state.config.breaker_cooldown.as_secs(),
+                timezone: self.state.config.timezone.to_string(),
+                snapshot_cache: self.state.cache.stats(),
+                breaker: self.state.breaker.status(),
+                remindctl_capabilities: self.state.capabilities.report(),
             };
             let text = serde_json::to_string(&config).map_err(to_mcp_error)?;
             return Ok(ReadResourceResult {
@@ -964,72 +1550,67 @@ impl ServerHandler for AppServer {
             });
         }
 
-        if let Some(filter) = uri
-            .as_str()
+        if uri.as_str() == "remindctl://diagnostics/schema" {
+            let text =
+                serde_json::to_string(&self.state.schema_drift.report()).map_err(to_mcp_error)?;
+            return Ok(ReadResourceResult {
+                contents: vec![ResourceContents::text(text, uri)],
+            });
+        }
+
+        let (path, params) = uri.split_once('?').unwrap_or((uri.as_str(), ""));
+        let read_params = || {
+            ResourceParams::parse(path, params)
+                .map_err(|err| McpError::invalid_params(err.to_string(), None))
+        };
+
+        if let Some(filter) = path
             .strip_prefix("remindctl://reminders/")
             .filter(|value| !value.is_empty())
         {
+            let params = read_params()?;
             let reminders = self
                 .state
-                .runner
-                .run_read_json::<Vec<Reminder>>(vec!["show".to_owned(), filter.to_owned()])
+                .backend
+                .show(filter, None)
                 .await
                 .map_err(to_mcp_error)?;
-            let text = serde_json::to_string(&reminders).map_err(to_mcp_error)?;
-            return Ok(ReadResourceResult {
-                contents: vec![ResourceContents::text(text, uri)],
-            });
+            return self.reminders_resource(&uri, reminders, &params);
         }
 
-        if let Some(list_id) = uri
-            .as_str()
-            .strip_prefix("remindctl://lists/")
+        if let Some(list_name) = path
+            .strip_prefix("remindctl://lists/by-name/")
             .and_then(|rest| rest.strip_suffix("/reminders"))
             .filter(|value| !value.is_empty())
         {
-            let lists = self.fetch_lists().await.map_err(to_mcp_error)?;
-            let list_name = resolve_list_name(&lists, Some(list_id), None).map_err(to_mcp_error)?;
-            let Some(list_name) = list_name else {
-                return Err(to_mcp_error("list not found"));
-            };
+            let params = read_params()?;
             let reminders = self
                 .state
-                .runner
-                .run_read_json::<Vec<Reminder>>(vec![
-                    "show".to_owned(),
-                    "all".to_owned(),
-                    "--list".to_owned(),
-                    list_name,
-                ])
+                .backend
+                .show("all", Some(list_name))
                 .await
                 .map_err(to_mcp_error)?;
-            let text = serde_json::to_string(&reminders).map_err(to_mcp_error)?;
-            return Ok(ReadResourceResult {
-                contents: vec![ResourceContents::text(text, uri)],
-            });
+            return self.reminders_resource(&uri, reminders, &params);
         }
 
-        if let Some(list_name) = uri
-            .as_str()
-            .strip_prefix("remindctl://lists/by-name/")
+        if let Some(list_id) = path
+            .strip_prefix("remindctl://lists/")
             .and_then(|rest| rest.strip_suffix("/reminders"))
             .filter(|value| !value.is_empty())
         {
+            let params = read_params()?;
+            let lists = self.fetch_lists().await.map_err(to_mcp_error)?;
+            let list_name = resolve_list_name(&lists, Some(list_id), None).map_err(to_mcp_error)?;
+            let Some(list_name) = list_name else {
+                return Err(to_mcp_error("list not found"));
+            };
             let reminders = self
                 .state
-                .runner
-                .run_read_json::<Vec<Reminder>>(vec![
-                    "show".to_owned(),
-                    "all".to_owned(),
-                    "--list".to_owned(),
-                    list_name.to_owned(),
-                ])
+                .backend
+                .show("all", Some(&list_name))
                 .await
                 .map_err(to_mcp_error)?;
-            let text = serde_json::to_string(&reminders).map_err(to_mcp_error)?;
-            return Ok(ReadResourceResult {
-                contents: vec![ResourceContents::text(text, uri)],
-            });
+            return self.reminders_resource(&uri, reminders, &params);
         }
 
         Err(McpError::resource_not_found(
@@ -1039,6 +1620,26 @@ impl ServerHandler for AppServer {
     }
 }
 
+/// Streamable HTTP MCP service at `/mcp`, behind the API key middleware.
+pub fn router(state: Arc<RuntimeState>, cancellation_token: CancellationToken) -> Router {
+    let mcp_service: StreamableHttpService<AppServer, LocalSessionManager> =
+        StreamableHttpService::new(
+            {
+                let state = Arc::clone(&state);
+                move || Ok(AppServer::new(Arc::clone(&state)))
+            },
+            LocalSessionManager::default().into(),
+            StreamableHttpServerConfig {
+                cancellation_token,
+                ..Default::default()
+            },
+        );
+
+    Router::new()
+        .nest_service("/mcp", mcp_service)
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
+
 pub async fn auth_middleware(
     State(state): State<Arc<RuntimeState>>,
     headers: HeaderMap,
@@ -1065,8 +1666,162 @@ pub async fn auth_middleware(
     }
 }
 
-fn tool_error(error: AppError) -> String {
-    error.to_string()
+fn log_capabilities(capabilities: &Capabilities) {
+    let report = capabilities.report();
+    if !report.probed {
+        tracing::warn!("could not probe remindctl capabilities, assuming all flags are supported");
+        return;
+    }
+    tracing::info!(version = ?report.version, "detected remindctl");
+    if !report.unsupported.is_empty() {
+        tracing::warn!(
+            unsupported = ?report.unsupported,
+            "installed remindctl lacks features some tools use",
+        );
+    }
+}
+
+fn require_due_for_relative(alarms: &[Alarm], has_due: bool) -> Result<(), AppError> {
+    if !has_due && alarms.iter().any(Alarm::is_relative) {
+        return Err(AppError::invalid_field(
+            "alarms",
+            "relative alarms need a due date; pass due or use an absolute time",
+        ));
+    }
+    Ok(())
+}
+
+fn parse_priority(
+    input: Option<&PriorityInput>,
+    field: &str,
+) -> Result<Option<Priority>, AppError> {
+    input.map(|input| Priority::parse(input, field)).transpose()
+}
+
+fn tool_error(error: AppError) -> ToolError {
+    ToolError {
+        code: error.code().to_owned(),
+        message: error.to_string(),
+        retryable: error.is_retryable(),
+        field: error.field().map(str::to_owned),
+        candidates: error.candidates().to_vec(),
+    }
+}
+
+/// Tool failures are returned as a JSON text block so agents can branch on `code`
+/// and pick from `candidates` without parsing English.
+impl IntoContents for ToolError {
+    fn into_contents(self) -> Vec<Content> {
+        let text = serde_json::to_string(&self).unwrap_or(self.message);
+        vec![Content::text(text)]
+    }
+}
+
+/// Options a reminder resource URI takes after `?`: paging (`sortBy`, `order`, `limit`,
+/// `cursor`) and shaping (`fields`, `format`).
+struct ResourceParams {
+    /// `None` without paging options, so plain reads keep returning a bare array.
+    page: Option<PageRequest>,
+    projection: Projection,
+}
+
+impl ResourceParams {
+    fn parse(path: &str, params: &str) -> Result<Self, AppError> {
+        let mut request = PageRequest {
+            scope: paging::fingerprint(&["resource", path]),
+            ..PageRequest::default()
+        };
+        let mut paged = false;
+        let (mut fields, mut format) = (None, None);
+        for pair in params.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let (Some(key), Some(value)) = (percent_decode(key), percent_decode(value)) else {
+                return Err(AppError::invalid_input(format!(
+                    "'{pair}' is not valid percent-encoding"
+                )));
+            };
+            let (key, value) = (key.as_str(), value.as_str());
+            match key {
+                "sortBy" => request.sort_by = Some(named_param(key, value)?),
+                "order" => request.order = Some(named_param(key, value)?),
+                "limit" => {
+                    request.limit = Some(value.parse().map_err(|_| {
+                        AppError::invalid_field("limit", format!("limit '{value}' is not a number"))
+                    })?)
+                }
+                "cursor" => request.cursor = Some(value.to_owned()),
+                "fields" => fields = Some(vec![value.to_owned()]),
+                "format" => format = Some(named_param(key, value)?),
+                _ => {
+                    return Err(AppError::invalid_field(
+                        key,
+                        format!(
+                            "unknown parameter '{key}'; use sortBy, order, limit, cursor, fields or format"
+                        ),
+                    ));
+                }
+            }
+            paged |= !matches!(key, "fields" | "format");
+        }
+        Ok(Self {
+            page: paged.then_some(request),
+            projection: Projection::parse(fields.as_deref(), format)?,
+        })
+    }
+}
+
+/// Decodes a query-string key or value: `%XX` escapes and `+` for a space.
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'%' => {
+                let hex = tail
+                    .get(..2)
+                    .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))?;
+                bytes.push(u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()?);
+                rest = &tail[2..];
+            }
+            b'+' => bytes.push(b' '),
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+fn named_param<T: serde::de::DeserializeOwned>(key: &str, value: &str) -> Result<T, AppError> {
+    serde_json::from_value(Value::String(value.to_owned()))
+        .map_err(|_| AppError::invalid_field(key, format!("unknown {key} '{value}'")))
+}
+
+fn tool_failure(error: ToolError) -> CallToolResult {
+    CallToolResult::error(error.into_contents())
+}
+
+/// A shaped read result: `table` replaces the JSON text content when present.
+fn shaped_result(structured: Value, table: Option<String>) -> CallToolResult {
+    let mut result = CallToolResult::structured(structured);
+    if let Some(table) = table {
+        result.content = vec![Content::text(table)];
+    }
+    result
+}
+
+/// `T`'s output schema with every reminder field optional, since `fields` and
+/// `compact` may drop or rename any of them.
+fn shaped_schema<T: JsonSchema + 'static>() -> Arc<JsonObject> {
+    let mut schema = schema_for_output::<T>()
+        .map(|schema| (*schema).clone())
+        .unwrap_or_default();
+    if let Some(Value::Object(reminder)) = schema
+        .get_mut("$defs")
+        .and_then(|defs| defs.get_mut("Reminder"))
+    {
+        reminder.remove("required");
+    }
+    Arc::new(schema)
 }
 
 fn to_mcp_error(error: impl ToString) -> McpError {
@@ -1075,7 +1830,34 @@ fn to_mcp_error(error: impl ToString) -> McpError {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
+    use crate::dates::ServerTimezone;
+
+    fn mk_memory_server() -> AppServer {
+        let config = Config {
+            bind_addr: ([127, 0, 0, 1], 0).into(),
+            auth_required: false,
+            api_key: None,
+            backend: BackendKind::Memory,
+            memory_fixture: None,
+            remindctl_bin: "remindctl".to_owned(),
+            fixture_mode: FixtureMode::Off,
+            fixture_dir: None,
+            read_timeout: Duration::from_secs(1),
+            write_timeout: Duration::from_secs(1),
+            cache_ttl: Duration::from_secs(5),
+            max_concurrent_reads: 4,
+            max_retries: 0,
+            retry_base_delay: Duration::ZERO,
+            breaker_threshold: 0,
+            breaker_cooldown: Duration::ZERO,
+            timezone: ServerTimezone::Local,
+        };
+        let state = RuntimeState::with_backend(config, Arc::new(MemoryBackend::new()));
+        AppServer::new(Arc::new(state))
+    }
 
     fn mk_list(id: &str, title: &str) -> ReminderList {
         ReminderList {
@@ -1121,4 +1903,50 @@ mod tests {
         let selected = AppServer::infer_best_list_name(&lists, "Comprar Coca Zero lata", None);
         assert_eq!(selected.as_deref(), Some("Compras"));
     }
+
+    #[tokio::test]
+    async fn batch_add_then_delete_recent_on_memory_backend() -> Result<(), ToolError> {
+        let server = mk_memory_server();
+        let input = BatchProcessInput {
+            actions: vec![
+                BatchActionInput {
+                    id: "q1".to_owned(),
+                    op: "reminder_add".to_owned(),
+                    args: serde_json::json!({ "title": "Comprar leche" }),
+                },
+                BatchActionInput {
+                    id: "q2".to_owned(),
+                    op: "reminder_delete".to_owned(),
+                    args: serde_json::json!({}),
+                },
+            ],
+            stop_on_error: Some(true),
+        };
+
+        let result = server.process_pending_actions(Parameters(input)).await?.0;
+        assert_eq!(result.succeeded, 2, "results: {:?}", result.results);
+
+        let remaining = server.fetch_all_reminders().await.map_err(tool_error)?;
+        assert!(remaining.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_failures_carry_error_code() -> Result<(), ToolError> {
+        let server = mk_memory_server();
+        let input = BatchProcessInput {
+            actions: vec![BatchActionInput {
+                id: "q1".to_owned(),
+                op: "reminder_edit".to_owned(),
+                args: serde_json::json!({ "reminderId": "ZZZZ-0000", "title": "Nope" }),
+            }],
+            stop_on_error: None,
+        };
+
+        let result = server.process_pending_actions(Parameters(input)).await?.0;
+        let failure = &result.results[0];
+        assert_eq!(failure.code.as_deref(), Some("reminder_not_found"));
+        assert_eq!(failure.retryable, Some(false));
+        Ok(())
+    }
 }
@@ -0,0 +1,34 @@
+/// The base letter of a lowercase accented Latin letter, so "café" compares equal to "cafe".
+pub fn strip_accent(ch: char) -> char {
+    match ch {
+        'á' | 'à' | 'ä' | 'â' | 'ã' | 'å' => 'a',
+        'é' | 'è' | 'ë' | 'ê' => 'e',
+        'í' | 'ì' | 'ï' | 'î' => 'i',
+        'ó' | 'ò' | 'ö' | 'ô' | 'õ' => 'o',
+        'ú' | 'ù' | 'ü' | 'û' => 'u',
+        'ñ' => 'n',
+        'ç' => 'c',
+        other => other,
+    }
+}
+
+/// One character lowercased with accents stripped; may be several characters.
+pub fn fold_char(ch: char) -> impl Iterator<Item = char> {
+    ch.to_lowercase().map(strip_accent)
+}
+
+/// Lowercased, accent-stripped text, for case- and accent-insensitive comparisons.
+pub fn fold(text: &str) -> String {
+    text.chars().flat_map(fold_char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_case_and_accents() {
+        assert_eq!(fold("Mañana CAFÉ Çà"), "manana cafe ca");
+        assert_eq!(fold("İ").chars().count(), 2);
+    }
+}
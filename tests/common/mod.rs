@@ -0,0 +1,286 @@
+#![allow(dead_code)]
+
+use std::fs;
+use std::net::SocketAddr;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use remindctl_mcp::config::{BackendKind, Config, FixtureMode};
+use remindctl_mcp::dates::ServerTimezone;
+use remindctl_mcp::server::{RuntimeState, router};
+use rmcp::model::{CallToolRequestParams, CallToolResult};
+use rmcp::service::RunningService;
+use rmcp::transport::StreamableHttpClientTransport;
+use rmcp::transport::streamable_http_client::StreamableHttpClientTransportConfig;
+use rmcp::{RoleClient, ServiceExt};
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+
+pub const TEST_API_KEY: &str = "test-key";
+
+/// Every `remindctl` call gets these appended by the runner.
+pub const SAFE_FLAGS: [&str; 3] = ["--json", "--no-input", "--no-color"];
+
+const FAKE_SCRIPT: &str = r#"#!/bin/sh
+dir=$(dirname "$0")
+echo $$ >> "$dir/pids.log"
+{ for arg in "$@"; do printf '%s\037' "$arg"; done; printf '\n'; } >> "$dir/calls.log"
+cmd=$1
+if [ "$2" = "--help" ]; then cmd="$1.help"; fi
+if [ -f "$dir/responses/$cmd.sleep" ]; then sleep "$(cat "$dir/responses/$cmd.sleep")"; fi
+if [ -f "$dir/responses/$cmd.stderr" ]; then cat "$dir/responses/$cmd.stderr" >&2; fi
+if [ -f "$dir/responses/$cmd.json" ]; then cat "$dir/responses/$cmd.json"; fi
+if [ -f "$dir/responses/$cmd.exit" ]; then exit "$(cat "$dir/responses/$cmd.exit")"; fi
+exit 0
+"#;
+
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+/// Scriptable stand-in for `remindctl`. Responses are keyed by the first argument
+/// (`status`, `list`, `show`, `add`, ...) and every invocation's argv is recorded.
+pub struct FakeRemindctl {
+    dir: PathBuf,
+}
+
+impl FakeRemindctl {
+    pub fn new() -> Result<Self> {
+        let dir = std::env::temp_dir().join(format!(
+            "remindctl-mcp-test-{}-{}",
+            std::process::id(),
+            NEXT_DIR.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::create_dir_all(dir.join("responses"))?;
+        let bin = dir.join("remindctl");
+        fs::write(&bin, FAKE_SCRIPT)?;
+        fs::set_permissions(&bin, fs::Permissions::from_mode(0o755))?;
+        Ok(Self { dir })
+    }
+
+    pub fn bin(&self) -> PathBuf {
+        self.dir.join("remindctl")
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn respond(&self, command: &str, body: Value) -> Result<()> {
+        fs::write(self.response_path(command, "json"), body.to_string())?;
+        Ok(())
+    }
+
+    /// Plain-text stdout, e.g. for `--version` or `edit.help` (`edit --help`).
+    pub fn respond_text(&self, command: &str, text: &str) -> Result<()> {
+        fs::write(self.response_path(command, "json"), text)?;
+        Ok(())
+    }
+
+    /// Forgets logged calls, e.g. the capability probes made at startup.
+    pub fn reset_calls(&self) -> Result<()> {
+        for log in ["calls.log", "pids.log"] {
+            match fs::remove_file(self.dir.join(log)) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(err.into()),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn fail(&self, command: &str, exit_code: i32, stderr: &str) -> Result<()> {
+        fs::write(self.response_path(command, "exit"), exit_code.to_string())?;
+        fs::write(self.response_path(command, "stderr"), stderr)?;
+        Ok(())
+    }
+
+    /// Makes `command` sleep before answering, to hold calls in flight.
+    pub fn delay(&self, command: &str, seconds: f32) -> Result<()> {
+        fs::write(self.response_path(command, "sleep"), seconds.to_string())?;
+        Ok(())
+    }
+
+    /// Pids of every fake invocation; each is also its process group id.
+    pub fn pids(&self) -> Result<Vec<u32>> {
+        let log = match fs::read_to_string(self.dir.join("pids.log")) {
+            Ok(log) => log,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        log.lines()
+            .map(|line| {
+                line.trim()
+                    .parse::<u32>()
+                    .context("invalid pid in pids.log")
+            })
+            .collect()
+    }
+
+    /// Argv of every invocation since the server started, in call order.
+    pub fn calls(&self) -> Result<Vec<Vec<String>>> {
+        let log = match fs::read_to_string(self.dir.join("calls.log")) {
+            Ok(log) => log,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(log
+            .lines()
+            .map(|line| {
+                line.split_terminator('\u{1f}')
+                    .map(str::to_owned)
+                    .collect::<Vec<_>>()
+            })
+            .collect())
+    }
+
+    fn response_path(&self, command: &str, extension: &str) -> PathBuf {
+        self.dir
+            .join("responses")
+            .join(format!("{command}.{extension}"))
+    }
+}
+
+impl Drop for FakeRemindctl {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Builds the argv the fake should have received for `args`, safe flags included.
+pub fn argv(args: &[&str]) -> Vec<String> {
+    args.iter()
+        .chain(SAFE_FLAGS.iter())
+        .map(|arg| (*arg).to_owned())
+        .collect()
+}
+
+pub fn test_config(fake: &FakeRemindctl) -> Config {
+    Config {
+        bind_addr: ([127, 0, 0, 1], 0).into(),
+        auth_required: true,
+        api_key: Some(TEST_API_KEY.to_owned()),
+        backend: BackendKind::Remindctl,
+        memory_fixture: None,
+        remindctl_bin: fake.bin().to_string_lossy().into_owned(),
+        fixture_mode: FixtureMode::Off,
+        fixture_dir: None,
+        read_timeout: Duration::from_secs(5),
+        write_timeout: Duration::from_secs(5),
+        cache_ttl: Duration::from_secs(5),
+        max_concurrent_reads: 4,
+        max_retries: 0,
+        retry_base_delay: Duration::ZERO,
+        breaker_threshold: 0,
+        breaker_cooldown: Duration::ZERO,
+        timezone: ServerTimezone::Local,
+    }
+}
+
+/// Full axum router served on an ephemeral port.
+pub struct TestServer {
+    pub addr: SocketAddr,
+    pub fake: FakeRemindctl,
+    shutdown: CancellationToken,
+}
+
+impl TestServer {
+    pub async fn start() -> Result<Self> {
+        let fake = FakeRemindctl::new()?;
+        let config = test_config(&fake);
+        Self::start_with(fake, config).await
+    }
+
+    pub async fn start_with(fake: FakeRemindctl, config: Config) -> Result<Self> {
+        let state = Arc::new(RuntimeState::new(config).await?);
+        fake.reset_calls()?;
+        let shutdown = CancellationToken::new();
+        let app = router(state, shutdown.child_token());
+
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0)).await?;
+        let addr = listener.local_addr()?;
+        let graceful = shutdown.clone();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app)
+                .with_graceful_shutdown(async move { graceful.cancelled().await })
+                .await;
+        });
+
+        Ok(Self {
+            addr,
+            fake,
+            shutdown,
+        })
+    }
+
+    pub async fn client(&self) -> Result<RunningService<RoleClient, ()>> {
+        self.client_with_key(Some(TEST_API_KEY)).await
+    }
+
+    pub async fn client_with_key(
+        &self,
+        api_key: Option<&str>,
+    ) -> Result<RunningService<RoleClient, ()>> {
+        let mut config =
+            StreamableHttpClientTransportConfig::with_uri(format!("http://{}/mcp", self.addr));
+        if let Some(key) = api_key {
+            config = config.auth_header(key);
+        }
+        let transport = StreamableHttpClientTransport::from_config(config);
+        ().serve(transport)
+            .await
+            .map_err(|err| anyhow!("mcp client initialization failed: {err}"))
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.shutdown.cancel();
+    }
+}
+
+pub async fn call_tool(
+    client: &RunningService<RoleClient, ()>,
+    name: &str,
+    arguments: Value,
+) -> Result<CallToolResult> {
+    let arguments = match arguments {
+        Value::Object(map) => Some(map),
+        Value::Null => None,
+        other => return Err(anyhow!("tool arguments must be an object, got {other}")),
+    };
+    client
+        .call_tool(CallToolRequestParams {
+            meta: None,
+            name: name.to_owned().into(),
+            arguments,
+            task: None,
+        })
+        .await
+        .with_context(|| format!("calling tool {name}"))
+}
+
+/// First text content block of a tool result.
+pub fn text_content(result: &CallToolResult) -> Option<String> {
+    result
+        .content
+        .iter()
+        .find_map(|content| content.as_text().map(|text| text.text.clone()))
+}
+
+/// Live (non-zombie) processes whose process group is `pgid`, via `ps`.
+pub fn live_group_members(pgid: u32) -> Result<Vec<String>> {
+    let output = std::process::Command::new("ps")
+        .args(["-A", "-o", "pid=,pgid=,stat="])
+        .output()?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            matches!(fields.as_slice(), [_, group, stat, ..]
+                if group.parse::<u32>().ok() == Some(pgid) && !stat.starts_with('Z'))
+        })
+        .map(str::to_owned)
+        .collect())
+}
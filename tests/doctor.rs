@@ -0,0 +1,83 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::process::{Command, Output};
+
+use anyhow::Result;
+use common::{FakeRemindctl, TEST_API_KEY};
+use serde_json::json;
+
+fn run_doctor(fake: &FakeRemindctl, extra_env: &[(&str, &str)]) -> Result<Output> {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_remindctl-mcp"));
+    cmd.arg("doctor")
+        .env_clear()
+        .env("PATH", std::env::var("PATH").unwrap_or_default())
+        .env("BIND_ADDR", "127.0.0.1:0")
+        .env("API_KEY", TEST_API_KEY)
+        .env("REMINDCTL_BIN", fake.bin());
+    for (key, value) in extra_env {
+        cmd.env(key, value);
+    }
+    Ok(cmd.output()?)
+}
+
+fn healthy_fake() -> Result<FakeRemindctl> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond_text("--version", "remindctl 0.4.0\n")?;
+    fake.respond(
+        "status",
+        json!({ "authorized": true, "status": "authorized" }),
+    )?;
+    fake.respond("list", json!([{ "id": "L-1", "title": "Inbox" }]))?;
+    fake.respond("show", json!([]))?;
+    Ok(fake)
+}
+
+#[test]
+fn healthy_host_passes() -> Result<()> {
+    let fake = healthy_fake()?;
+
+    let output = run_doctor(&fake, &[])?;
+
+    let report = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{report}");
+    assert!(report.contains("[PASS] authorization"), "{report}");
+    assert!(
+        report.contains("[PASS] list output: parsed 1 lists"),
+        "{report}"
+    );
+    Ok(())
+}
+
+#[test]
+fn unauthorized_remindctl_fails_with_fix() -> Result<()> {
+    let fake = healthy_fake()?;
+    fake.respond("status", json!({ "authorized": false, "status": "denied" }))?;
+
+    let output = run_doctor(&fake, &[])?;
+
+    let report = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1), "{report}");
+    assert!(
+        report.contains("[FAIL] authorization: remindctl status: denied"),
+        "{report}"
+    );
+    assert!(report.contains("fix: "), "{report}");
+    Ok(())
+}
+
+#[test]
+fn open_non_loopback_bind_is_flagged() -> Result<()> {
+    let fake = healthy_fake()?;
+
+    let output = run_doctor(
+        &fake,
+        &[("AUTH_REQUIRED", "false"), ("BIND_ADDR", "0.0.0.0:0")],
+    )?;
+
+    let report = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success(), "{report}");
+    assert!(report.contains("[FAIL] exposure"), "{report}");
+    Ok(())
+}
@@ -0,0 +1,1315 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use common::{
+    FakeRemindctl, TestServer, argv, call_tool, live_group_members, test_config, text_content,
+};
+use remindctl_mcp::config::FixtureMode;
+use remindctl_mcp::dates::ServerTimezone;
+use rmcp::model::{ReadResourceRequestParams, ResourceContents};
+use serde_json::json;
+
+fn reminder(id: &str, title: &str, list: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "title": title,
+        "listID": "L-1",
+        "listName": list,
+        "isCompleted": false,
+        "priority": "none",
+        "dueDate": null,
+        "notes": ""
+    })
+}
+
+#[tokio::test]
+async fn rejects_sessions_without_api_key() -> Result<()> {
+    let server = TestServer::start().await?;
+
+    assert!(server.client_with_key(None).await.is_err());
+    assert!(server.client_with_key(Some("wrong")).await.is_err());
+    assert!(server.fake.calls()?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn server_health_runs_status_with_safe_flags() -> Result<()> {
+    let server = TestServer::start().await?;
+    server.fake.respond(
+        "status",
+        json!({ "authorized": true, "status": "authorized" }),
+    )?;
+    let client = server.client().await?;
+
+    let result = call_tool(&client, "server_health", json!({})).await?;
+
+    assert_ne!(result.is_error, Some(true));
+    let payload = result.structured_content.unwrap_or_default();
+    assert_eq!(payload["ok"], json!(true));
+    assert_eq!(payload["authRequired"], json!(true));
+    assert_eq!(payload["remindctlAuthorized"], json!(true));
+    assert_eq!(payload["remindctlStatus"], json!("authorized"));
+    assert_eq!(server.fake.calls()?, vec![argv(&["status"])]);
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn reminder_add_resolves_list_id_to_name() -> Result<()> {
+    let server = TestServer::start().await?;
+    server.fake.respond(
+        "list",
+        json!([{ "id": "L-1", "title": "Compras" }, { "id": "L-2", "title": "Work" }]),
+    )?;
+    server
+        .fake
+        .respond("add", reminder("AAAA-1111", "Leche", "Compras"))?;
+    let client = server.client().await?;
+
+    let result = call_tool(
+        &client,
+        "reminder_add",
+        json!({ "title": "Leche", "listId": "L-1", "due": "2026-03-01", "priority": "high" }),
+    )
+    .await?;
+
+    assert_ne!(result.is_error, Some(true), "{:?}", text_content(&result));
+    assert_eq!(
+        server.fake.calls()?,
+        vec![
+            argv(&["list"]),
+            argv(&[
+                "add",
+                "--title",
+                "Leche",
+                "--list",
+                "Compras",
+                "--due",
+                "2026-03-01",
+                "--priority",
+                "high"
+            ]),
+        ]
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn reminder_delete_resolves_prefix_and_forces() -> Result<()> {
+    let server = TestServer::start().await?;
+    server.fake.respond(
+        "show",
+        json!([
+            reminder("AAAA-1111", "One", "Inbox"),
+            reminder("BBBB-2222", "Two", "Inbox")
+        ]),
+    )?;
+    server
+        .fake
+        .respond("delete", json!([reminder("BBBB-2222", "Two", "Inbox")]))?;
+    let client = server.client().await?;
+
+    let result = call_tool(
+        &client,
+        "reminder_delete",
+        json!({ "reminderIds": ["bbbb", "CCCC"] }),
+    )
+    .await?;
+
+    assert_ne!(result.is_error, Some(true), "{:?}", text_content(&result));
+    let payload = result.structured_content.unwrap_or_default();
+    assert_eq!(payload["deletedIds"], json!(["BBBB-2222"]));
+    assert_eq!(payload["alreadyAbsentRefs"], json!(["CCCC"]));
+    assert_eq!(
+        server.fake.calls()?,
+        vec![
+            argv(&["show", "all"]),
+            argv(&["delete", "BBBB-2222", "--force"]),
+        ]
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn command_failure_becomes_tool_error() -> Result<()> {
+    let server = TestServer::start().await?;
+    server.fake.fail("list", 1, "Reminders access denied")?;
+    let client = server.client().await?;
+
+    let result = call_tool(&client, "lists_list", json!({})).await?;
+
+    assert_eq!(result.is_error, Some(true));
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&result).unwrap_or_default())?;
+    assert_eq!(error["code"], json!("not_authorized"));
+    assert_eq!(error["retryable"], json!(false));
+    let message = error["message"].as_str().unwrap_or_default();
+    assert!(message.contains("Reminders access denied"), "{message}");
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn recorded_session_replays_without_binary() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond("list", json!([{ "id": "L-1", "title": "Compras" }]))?;
+    let fixture_dir = fake.dir().join("fixtures");
+    let mut config = test_config(&fake);
+    config.fixture_mode = FixtureMode::Record;
+    config.fixture_dir = Some(fixture_dir.clone());
+    let recorder = TestServer::start_with(fake, config).await?;
+
+    let client = recorder.client().await?;
+    let recorded = call_tool(&client, "lists_list", json!({})).await?;
+    client.cancel().await?;
+
+    let replay_fake = FakeRemindctl::new()?;
+    let mut config = test_config(&replay_fake);
+    config.remindctl_bin = "/nonexistent/remindctl".to_owned();
+    config.fixture_mode = FixtureMode::Replay;
+    config.fixture_dir = Some(fixture_dir);
+    let replayer = TestServer::start_with(replay_fake, config).await?;
+
+    let client = replayer.client().await?;
+    let replayed = call_tool(&client, "lists_list", json!({})).await?;
+    let missing = call_tool(&client, "reminders_list", json!({})).await?;
+    client.cancel().await?;
+
+    assert_ne!(
+        recorded.is_error,
+        Some(true),
+        "{:?}",
+        text_content(&recorded)
+    );
+    assert_eq!(replayed.structured_content, recorded.structured_content);
+    assert_eq!(missing.is_error, Some(true));
+    Ok(())
+}
+
+#[tokio::test]
+async fn resolution_reuses_snapshot_across_tool_calls() -> Result<()> {
+    let server = TestServer::start().await?;
+    server
+        .fake
+        .respond("show", json!([reminder("AAAA-1111", "One", "Inbox")]))?;
+    server
+        .fake
+        .respond("complete", json!([reminder("AAAA-1111", "One", "Inbox")]))?;
+    let client = server.client().await?;
+
+    for _ in 0..2 {
+        let result = call_tool(
+            &client,
+            "reminder_complete",
+            json!({ "reminderId": "AAAA", "dryRun": true }),
+        )
+        .await?;
+        assert_ne!(result.is_error, Some(true), "{:?}", text_content(&result));
+    }
+
+    let show_calls = server
+        .fake
+        .calls()?
+        .into_iter()
+        .filter(|call| call.first().map(String::as_str) == Some("show"))
+        .count();
+    assert_eq!(show_calls, 1);
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_identical_reads_share_one_process() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond("list", json!([{ "id": "L-1", "title": "Compras" }]))?;
+    fake.delay("list", 0.3)?;
+    let mut config = test_config(&fake);
+    config.cache_ttl = Duration::ZERO;
+    let server = TestServer::start_with(fake, config).await?;
+    let client = server.client().await?;
+
+    let (a, b, c) = tokio::join!(
+        call_tool(&client, "lists_list", json!({})),
+        call_tool(&client, "lists_list", json!({})),
+        call_tool(&client, "lists_list", json!({})),
+    );
+
+    for result in [a?, b?, c?] {
+        assert_ne!(result.is_error, Some(true), "{:?}", text_content(&result));
+        assert_eq!(
+            result.structured_content.unwrap_or_default()["lists"][0]["title"],
+            "Compras"
+        );
+    }
+    assert_eq!(server.fake.calls()?, vec![argv(&["list"])]);
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn reads_after_a_write_do_not_join_reads_started_before_it() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond("list", json!([{ "id": "L-1", "title": "Inbox" }]))?;
+    fake.respond("show", json!([]))?;
+    fake.delay("show", 0.6)?;
+    fake.respond("add", reminder("AAAA-1111", "Water plants", "Inbox"))?;
+    let config = test_config(&fake);
+    let server = TestServer::start_with(fake, config).await?;
+    let client = server.client().await?;
+    server.fake.reset_calls()?;
+
+    let (before, after) = tokio::join!(
+        call_tool(&client, "reminders_list", json!({ "filter": "all" })),
+        async {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            let added =
+                call_tool(&client, "reminder_add", json!({ "title": "Water plants" })).await?;
+            assert_ne!(added.is_error, Some(true), "{:?}", text_content(&added));
+            call_tool(&client, "reminders_list", json!({ "filter": "all" })).await
+        },
+    );
+    assert_ne!(before?.is_error, Some(true));
+    assert_ne!(after?.is_error, Some(true));
+    let shows = server
+        .fake
+        .calls()?
+        .into_iter()
+        .filter(|call| call.first().map(String::as_str) == Some("show"))
+        .count();
+    assert_eq!(
+        shows, 2,
+        "the later read must not reuse the pre-write snapshot"
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_limit_queues_distinct_reads() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond("list", json!([]))?;
+    fake.respond("show", json!([]))?;
+    fake.delay("show", 0.25)?;
+    let mut config = test_config(&fake);
+    config.max_concurrent_reads = 1;
+    let server = TestServer::start_with(fake, config).await?;
+    let client = server.client().await?;
+
+    let started = Instant::now();
+    let (today, week) = tokio::join!(
+        call_tool(&client, "reminders_list", json!({ "filter": "today" })),
+        call_tool(&client, "reminders_list", json!({ "filter": "week" })),
+    );
+    let elapsed = started.elapsed();
+
+    assert_ne!(today?.is_error, Some(true));
+    assert_ne!(week?.is_error, Some(true));
+    assert!(elapsed >= Duration::from_millis(500), "{elapsed:?}");
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn transient_failures_retry_then_open_breaker() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.fail("status", 1, "Reminders database is busy")?;
+    let mut config = test_config(&fake);
+    config.max_retries = 1;
+    config.breaker_threshold = 2;
+    config.breaker_cooldown = Duration::from_secs(60);
+    let server = TestServer::start_with(fake, config).await?;
+    let client = server.client().await?;
+
+    let first = call_tool(&client, "server_health", json!({})).await?;
+    assert_eq!(first.is_error, Some(true));
+    assert_eq!(
+        server.fake.calls()?.len(),
+        2,
+        "one retry after the first failure"
+    );
+
+    let second = call_tool(&client, "server_health", json!({})).await?;
+    assert_ne!(second.is_error, Some(true));
+    let payload = second.structured_content.unwrap_or_default();
+    assert_eq!(payload["ok"], json!(false));
+    assert_eq!(payload["breaker"]["state"], json!("open"));
+    assert_eq!(payload["breaker"]["consecutiveFailures"], json!(2));
+    assert_eq!(server.fake.calls()?.len(), 2, "open breaker fails fast");
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_complete_is_not_retried() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond(
+        "show",
+        json!([reminder("AAAA-1111", "Water plants", "Inbox")]),
+    )?;
+    fake.fail("complete", 1, "Reminders database is busy")?;
+    let mut config = test_config(&fake);
+    config.max_retries = 2;
+    let server = TestServer::start_with(fake, config).await?;
+    let client = server.client().await?;
+
+    let result = call_tool(
+        &client,
+        "reminder_complete",
+        json!({ "reminderIds": ["AAAA-1111"] }),
+    )
+    .await?;
+    assert_eq!(result.is_error, Some(true));
+    let completes = server
+        .fake
+        .calls()?
+        .into_iter()
+        .filter(|call| call.first().map(String::as_str) == Some("complete"))
+        .count();
+    assert_eq!(
+        completes, 1,
+        "a recurring complete may have advanced the due date"
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_edit_that_completes_is_not_retried() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond(
+        "show",
+        json!([reminder("AAAA-1111", "Water plants", "Inbox")]),
+    )?;
+    fake.respond("list", json!([{ "id": "L-1", "title": "Inbox" }]))?;
+    fake.fail("edit", 1, "Reminders database is busy")?;
+    let mut config = test_config(&fake);
+    config.max_retries = 2;
+    let server = TestServer::start_with(fake, config).await?;
+    let client = server.client().await?;
+
+    let edits = |server: &TestServer| -> Result<usize> {
+        Ok(server
+            .fake
+            .calls()?
+            .into_iter()
+            .filter(|call| call.first().map(String::as_str) == Some("edit"))
+            .count())
+    };
+    let renamed = call_tool(
+        &client,
+        "reminder_edit",
+        json!({ "reminderId": "AAAA-1111", "title": "Water the plants" }),
+    )
+    .await?;
+    assert_eq!(renamed.is_error, Some(true));
+    assert_eq!(edits(&server)?, 3, "plain edits are retried");
+
+    let completed = call_tool(
+        &client,
+        "reminder_edit",
+        json!({ "reminderId": "AAAA-1111", "complete": true }),
+    )
+    .await?;
+    assert_eq!(completed.is_error, Some(true));
+    assert_eq!(
+        edits(&server)?,
+        4,
+        "a recurring complete may have advanced the due date"
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn timed_out_remindctl_is_killed_with_its_children() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.delay("status", 30.0)?;
+    let mut config = test_config(&fake);
+    config.read_timeout = Duration::from_millis(300);
+    let server = TestServer::start_with(fake, config).await?;
+    let client = server.client().await?;
+
+    let started = Instant::now();
+    let result = call_tool(&client, "server_health", json!({})).await?;
+    assert_eq!(result.is_error, Some(true));
+    assert!(
+        started.elapsed() < Duration::from_secs(5),
+        "{:?}",
+        started.elapsed()
+    );
+
+    let pids = server.fake.pids()?;
+    assert_eq!(pids.len(), 1);
+    let mut survivors = live_group_members(pids[0])?;
+    for _ in 0..20 {
+        if survivors.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        survivors = live_group_members(pids[0])?;
+    }
+    assert!(survivors.is_empty(), "orphaned processes: {survivors:?}");
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn ambiguous_ref_error_lists_candidates() -> Result<()> {
+    let server = TestServer::start().await?;
+    server.fake.respond(
+        "show",
+        json!([
+            reminder("AAAA-1111", "One", "Inbox"),
+            reminder("AAAA-2222", "Two", "Work")
+        ]),
+    )?;
+    let client = server.client().await?;
+
+    let result = call_tool(
+        &client,
+        "reminder_complete",
+        json!({ "reminderIds": ["aaaa"] }),
+    )
+    .await?;
+
+    assert_eq!(result.is_error, Some(true));
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&result).unwrap_or_default())?;
+    assert_eq!(error["code"], json!("ambiguous_ref"));
+    assert_eq!(
+        error["candidates"],
+        json!([
+            { "id": "AAAA-1111", "title": "One", "list": "Inbox", "due": null },
+            { "id": "AAAA-2222", "title": "Two", "list": "Work", "due": null }
+        ])
+    );
+    assert_eq!(server.fake.calls()?, vec![argv(&["show", "all"])]);
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn unsupported_flag_fails_before_running_remindctl() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond_text("--version", "remindctl 0.1.0\n")?;
+    fake.respond_text("edit.help", "OPTIONS:\n  --title <title>\n  --due <due>\n")?;
+    let config = test_config(&fake);
+    let server = TestServer::start_with(fake, config).await?;
+    server
+        .fake
+        .respond("show", json!([reminder("AAAA-1111", "One", "Inbox")]))?;
+    server.fake.respond(
+        "status",
+        json!({ "authorized": true, "status": "authorized" }),
+    )?;
+    server.fake.respond("list", json!([]))?;
+    let client = server.client().await?;
+
+    let health = call_tool(&client, "server_health", json!({})).await?;
+    let capabilities = &health.structured_content.unwrap_or_default()["capabilities"];
+    assert_eq!(capabilities["version"], json!("remindctl 0.1.0"));
+    assert_eq!(
+        capabilities["unsupported"],
+        json!([
+            "add --recurrence",
+            "add --alarm",
+            "edit --priority",
+            "edit --clear-due",
+            "edit --recurrence",
+            "edit --clear-recurrence",
+            "edit --alarm",
+            "edit --clear-alarms"
+        ])
+    );
+
+    let result = call_tool(
+        &client,
+        "reminder_edit",
+        json!({ "reminderId": "AAAA-1111", "clearDue": true }),
+    )
+    .await?;
+    assert_eq!(result.is_error, Some(true));
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&result).unwrap_or_default())?;
+    assert_eq!(error["code"], json!("unsupported"));
+    assert!(
+        !server.fake.calls()?.iter().any(|call| call[0] == "edit"),
+        "edit must not reach remindctl"
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn schema_diagnostics_report_unknown_keys() -> Result<()> {
+    let server = TestServer::start().await?;
+    let mut drifted = reminder("AAAA-1111", "One", "Inbox");
+    drifted["flagged"] = json!(true);
+    drifted["hasAlarms"] = json!(true);
+    server.fake.respond("show", json!([drifted]))?;
+    server.fake.respond("list", json!([]))?;
+    let client = server.client().await?;
+
+    let listed = call_tool(&client, "reminders_list", json!({ "filter": "all" })).await?;
+    assert_ne!(listed.is_error, Some(true), "{:?}", text_content(&listed));
+    let listed = listed.structured_content.unwrap_or_default();
+    assert_eq!(listed["reminders"][0]["flagged"], json!(true));
+    assert_eq!(
+        listed["reminders"][0]["hasAlarms"],
+        json!(true),
+        "extras pass through"
+    );
+
+    let resource = client
+        .read_resource(ReadResourceRequestParams {
+            meta: None,
+            uri: "remindctl://diagnostics/schema".to_owned(),
+        })
+        .await?;
+    let Some(ResourceContents::TextResourceContents { text, .. }) = resource.contents.first()
+    else {
+        anyhow::bail!("expected a text resource");
+    };
+    let report: serde_json::Value = serde_json::from_str(text)?;
+    assert_eq!(report["models"][0]["model"], json!("Reminder"));
+    assert_eq!(
+        report["models"][0]["unknownKeys"],
+        json!({ "hasAlarms": 1 })
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn due_is_validated_before_spawn_and_normalised_in_output() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    let mut config = test_config(&fake);
+    config.timezone = ServerTimezone::Named(chrono_tz::Europe::Madrid);
+    let server = TestServer::start_with(fake, config).await?;
+    server
+        .fake
+        .respond("list", json!([{ "id": "L-1", "title": "Inbox" }]))?;
+    let mut created = reminder("AAAA-1111", "Dentist", "Inbox");
+    created["dueDate"] = json!("2026-07-01T07:00:00Z");
+    server.fake.respond("add", created)?;
+    let client = server.client().await?;
+
+    let rejected = call_tool(
+        &client,
+        "reminder_edit",
+        json!({ "reminderId": "AAAA", "due": "next-ish" }),
+    )
+    .await?;
+    assert_eq!(rejected.is_error, Some(true));
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&rejected).unwrap_or_default())?;
+    assert_eq!(error["code"], json!("invalid_input"));
+    assert_eq!(error["field"], json!("due"));
+    assert!(server.fake.calls()?.is_empty());
+
+    let result = call_tool(
+        &client,
+        "reminder_add",
+        json!({ "title": "Dentist", "listName": "Inbox", "due": "2026-07-01T09:00" }),
+    )
+    .await?;
+    assert_ne!(result.is_error, Some(true), "{:?}", text_content(&result));
+    let calls = server.fake.calls()?;
+    assert_eq!(
+        calls.last(),
+        Some(&argv(&[
+            "add",
+            "--title",
+            "Dentist",
+            "--list",
+            "Inbox",
+            "--due",
+            "2026-07-01T07:00:00Z"
+        ]))
+    );
+    let payload = result.structured_content.unwrap_or_default();
+    assert_eq!(
+        payload["due"],
+        json!({
+            "original": "2026-07-01T07:00:00Z",
+            "utc": "2026-07-01T07:00:00Z",
+            "local": "2026-07-01T09:00:00+02:00",
+            "date": "2026-07-01",
+            "allDay": false,
+            "timezone": "Europe/Madrid",
+            "input": "2026-07-01T09:00"
+        })
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn spanish_due_phrase_is_resolved_and_echoed() -> Result<()> {
+    let server = TestServer::start().await?;
+    server
+        .fake
+        .respond("show", json!([reminder("AAAA-1111", "Dentista", "Inbox")]))?;
+    server.fake.respond("list", json!([]))?;
+    server
+        .fake
+        .respond("edit", reminder("AAAA-1111", "Dentista", "Inbox"))?;
+    let client = server.client().await?;
+
+    let result = call_tool(
+        &client,
+        "reminder_edit",
+        json!({ "reminderId": "AAAA", "due": "mañana a las 9" }),
+    )
+    .await?;
+
+    assert_ne!(result.is_error, Some(true), "{:?}", text_content(&result));
+    let payload = result.structured_content.unwrap_or_default();
+    assert_eq!(payload["due"]["input"], json!("mañana a las 9"));
+    assert_eq!(payload["due"]["allDay"], json!(false));
+    let utc = payload["due"]["utc"]
+        .as_str()
+        .unwrap_or_default()
+        .to_owned();
+    let calls = server.fake.calls()?;
+    assert_eq!(
+        calls.last(),
+        Some(&argv(&["edit", "AAAA-1111", "--due", &utc]))
+    );
+    assert!(
+        payload["due"]["local"]
+            .as_str()
+            .is_some_and(|local| local.contains("T09:00:00"))
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn priority_spellings_map_and_lists_filter_and_sort_by_priority() -> Result<()> {
+    let server = TestServer::start().await?;
+    server
+        .fake
+        .respond("list", json!([{ "id": "L-1", "title": "Inbox" }]))?;
+    let mut low = reminder("AAAA-1111", "Low", "Inbox");
+    low["priority"] = json!("low");
+    let mut high = reminder("BBBB-2222", "High", "Inbox");
+    high["priority"] = json!(1);
+    let mut medium = reminder("CCCC-3333", "Medium", "Inbox");
+    medium["priority"] = json!("medium");
+    server.fake.respond("show", json!([low, high, medium]))?;
+    server
+        .fake
+        .respond("add", reminder("DDDD-4444", "Pagar", "Inbox"))?;
+    let client = server.client().await?;
+
+    let rejected = call_tool(
+        &client,
+        "reminder_add",
+        json!({ "title": "Pagar", "priority": "whenever" }),
+    )
+    .await?;
+    assert_eq!(rejected.is_error, Some(true));
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&rejected).unwrap_or_default())?;
+    assert_eq!(error["field"], json!("priority"));
+    assert!(server.fake.calls()?.is_empty());
+
+    let added = call_tool(
+        &client,
+        "reminder_add",
+        json!({ "title": "Pagar", "listName": "Inbox", "priority": "!!!" }),
+    )
+    .await?;
+    assert_ne!(added.is_error, Some(true), "{:?}", text_content(&added));
+    assert_eq!(
+        server.fake.calls()?.last(),
+        Some(&argv(&[
+            "add",
+            "--title",
+            "Pagar",
+            "--list",
+            "Inbox",
+            "--priority",
+            "high"
+        ]))
+    );
+
+    let listed = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "filter": "all", "minPriority": "media", "sortBy": "priority" }),
+    )
+    .await?;
+    let payload = listed.structured_content.unwrap_or_default();
+    let titles = payload["reminders"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|reminder| (reminder["title"].clone(), reminder["priority"].clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        titles,
+        vec![
+            (json!("High"), json!("high")),
+            (json!("Medium"), json!("medium"))
+        ]
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn recurrence_is_passed_as_rrule_read_back_and_expanded() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond_text(
+        "add.help",
+        "OPTIONS:\n  --due <due>\n  --recurrence <rule>\n",
+    )?;
+    let config = test_config(&fake);
+    let server = TestServer::start_with(fake, config).await?;
+    server
+        .fake
+        .respond("list", json!([{ "id": "L-1", "title": "Inbox" }]))?;
+    let mut weekly = reminder("AAAA-1111", "Standup", "Inbox");
+    weekly["dueDate"] = json!("2030-01-07");
+    weekly["recurrence"] = json!("RRULE:FREQ=WEEKLY;BYDAY=MO,TH");
+    server.fake.respond("show", json!([weekly.clone()]))?;
+    server.fake.respond("add", weekly)?;
+    let client = server.client().await?;
+
+    let rejected = call_tool(
+        &client,
+        "reminder_add",
+        json!({ "title": "Standup", "recurrence": "FREQ=HOURLY" }),
+    )
+    .await?;
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&rejected).unwrap_or_default())?;
+    assert_eq!(error["field"], json!("recurrence"));
+    assert!(server.fake.calls()?.is_empty());
+
+    let added = call_tool(
+        &client,
+        "reminder_add",
+        json!({
+            "title": "Standup",
+            "listName": "Inbox",
+            "recurrence": { "frequency": "weekly", "byDay": ["MO", "TH"] }
+        }),
+    )
+    .await?;
+    assert_ne!(added.is_error, Some(true), "{:?}", text_content(&added));
+    assert_eq!(
+        server.fake.calls()?.last(),
+        Some(&argv(&[
+            "add",
+            "--title",
+            "Standup",
+            "--list",
+            "Inbox",
+            "--recurrence",
+            "FREQ=WEEKLY;BYDAY=MO,TH"
+        ]))
+    );
+    assert_eq!(
+        added.structured_content.unwrap_or_default()["recurrence"],
+        json!({ "frequency": "weekly", "interval": 1, "byDay": ["MO", "TH"] })
+    );
+
+    let preview = call_tool(
+        &client,
+        "reminder_occurrences",
+        json!({ "reminderId": "AAAA", "count": 3 }),
+    )
+    .await?;
+    let payload = preview.structured_content.unwrap_or_default();
+    let dates = payload["occurrences"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|occurrence| occurrence["date"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dates,
+        vec![
+            json!("2030-01-07"),
+            json!("2030-01-10"),
+            json!("2030-01-14")
+        ]
+    );
+    assert_eq!(payload["rrule"], json!("FREQ=WEEKLY;BYDAY=MO,TH"));
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn alarms_are_validated_replaced_and_read_back() -> Result<()> {
+    let fake = FakeRemindctl::new()?;
+    fake.respond_text("add.help", "OPTIONS:\n  --due <due>\n  --alarm <alarm>\n")?;
+    fake.respond_text(
+        "edit.help",
+        "OPTIONS:\n  --due <due>\n  --alarm <alarm>\n  --clear-alarms\n",
+    )?;
+    let config = test_config(&fake);
+    let server = TestServer::start_with(fake, config).await?;
+    server
+        .fake
+        .respond("list", json!([{ "id": "L-1", "title": "Inbox" }]))?;
+    let mut dated = reminder("AAAA-1111", "Dentist", "Inbox");
+    dated["dueDate"] = json!("2030-01-07T09:00:00Z");
+    server.fake.respond("show", json!([dated.clone()]))?;
+    dated["alarms"] =
+        json!([{ "relativeOffset": -3600 }, { "absoluteDate": "2030-01-06T20:00:00Z" }]);
+    server.fake.respond("edit", dated)?;
+    let client = server.client().await?;
+
+    let rejected = call_tool(
+        &client,
+        "reminder_add",
+        json!({ "title": "Dentist", "alarms": ["15 minutes before"] }),
+    )
+    .await?;
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&rejected).unwrap_or_default())?;
+    assert_eq!(error["field"], json!("alarms"));
+    assert!(server.fake.calls()?.is_empty());
+
+    let edited = call_tool(
+        &client,
+        "reminder_edit",
+        json!({
+            "reminderId": "AAAA",
+            "alarms": ["1 hora antes", { "at": "2030-01-06T20:00:00Z" }]
+        }),
+    )
+    .await?;
+    assert_ne!(edited.is_error, Some(true), "{:?}", text_content(&edited));
+    assert_eq!(
+        server.fake.calls()?.last(),
+        Some(&argv(&[
+            "edit",
+            "AAAA-1111",
+            "--clear-alarms",
+            "--alarm",
+            "-60m",
+            "--alarm",
+            "2030-01-06T20:00:00Z"
+        ]))
+    );
+    assert_eq!(
+        edited.structured_content.unwrap_or_default()["alarms"],
+        json!([
+            { "type": "relative", "minutesBefore": 60 },
+            { "type": "absolute", "at": "2030-01-06T20:00:00Z" }
+        ])
+    );
+
+    call_tool(
+        &client,
+        "reminder_edit",
+        json!({ "reminderId": "AAAA-1111", "clearAlarms": true }),
+    )
+    .await?;
+    assert_eq!(
+        server.fake.calls()?.last(),
+        Some(&argv(&["edit", "AAAA-1111", "--clear-alarms"]))
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_folds_accents_tolerates_typos_and_ranks_with_highlights() -> Result<()> {
+    let server = TestServer::start().await?;
+    server
+        .fake
+        .respond("list", json!([{ "id": "L-1", "title": "Compras" }]))?;
+    let mut in_notes = reminder("AAAA-1111", "Supermercado", "Compras");
+    in_notes["notes"] = json!("leche y café");
+    let in_title = reminder("BBBB-2222", "Comprar CAFÉ molido", "Compras");
+    let mut done = reminder("CCCC-3333", "Cafe con Ana", "Compras");
+    done["isCompleted"] = json!(true);
+    let typo = reminder("DDDD-4444", "Llamar al banco", "Compras");
+    server
+        .fake
+        .respond("show", json!([in_notes, in_title, done, typo]))?;
+    let client = server.client().await?;
+
+    let found = call_tool(
+        &client,
+        "reminders_search",
+        json!({ "query": "Cafe", "listName": "Compras" }),
+    )
+    .await?;
+    assert_ne!(found.is_error, Some(true), "{:?}", text_content(&found));
+    assert_eq!(
+        server.fake.calls()?.last(),
+        Some(&argv(&["show", "all", "--list", "Compras"]))
+    );
+    let payload = found.structured_content.unwrap_or_default();
+    assert_eq!(payload["total"], json!(2));
+    let ids = payload["results"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|hit| hit["reminder"]["id"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![json!("BBBB-2222"), json!("AAAA-1111")]);
+    assert_eq!(
+        payload["results"][0]["highlights"][0],
+        json!({
+            "field": "title",
+            "start": 8,
+            "end": 12,
+            "text": "CAFÉ",
+            "term": "cafe",
+            "kind": "exact"
+        })
+    );
+
+    let fuzzy = call_tool(&client, "reminders_search", json!({ "query": "bancp" })).await?;
+    let payload = fuzzy.structured_content.unwrap_or_default();
+    assert_eq!(payload["results"][0]["reminder"]["id"], json!("DDDD-4444"));
+    assert_eq!(
+        payload["results"][0]["highlights"][0]["kind"],
+        json!("fuzzy")
+    );
+
+    let empty = call_tool(&client, "reminders_search", json!({ "query": "¿?" })).await?;
+    let error: serde_json::Value = serde_json::from_str(&text_content(&empty).unwrap_or_default())?;
+    assert_eq!(error["field"], json!("query"));
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_query_filters_the_snapshot_and_reports_bad_tokens() -> Result<()> {
+    let server = TestServer::start().await?;
+    server.fake.respond(
+        "list",
+        json!([{ "id": "L-1", "title": "Compras" }, { "id": "L-2", "title": "Work" }]),
+    )?;
+    let mut milk = reminder("AAAA-1111", "Leche", "Compras");
+    milk["listID"] = json!("L-1");
+    milk["priority"] = json!("high");
+    milk["dueDate"] = json!("2026-10-20");
+    milk["notes"] = json!("sin lactosa");
+    let mut bought = milk.clone();
+    bought["id"] = json!("BBBB-2222");
+    bought["isCompleted"] = json!(true);
+    let mut report = reminder("CCCC-3333", "Leche report", "Work");
+    report["listID"] = json!("L-2");
+    report["priority"] = json!("high");
+    server.fake.respond("show", json!([milk, bought, report]))?;
+    let client = server.client().await?;
+
+    let listed = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "query": "list:compras priority>=medium due<2026-11-01 !completed text:\"leche\" has:notes" }),
+    )
+    .await?;
+    assert_ne!(listed.is_error, Some(true), "{:?}", text_content(&listed));
+    let ids = |payload: serde_json::Value| {
+        payload["reminders"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|reminder| reminder["id"].clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        ids(listed.structured_content.unwrap_or_default()),
+        vec![json!("AAAA-1111")]
+    );
+
+    let structured = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "where": [{ "field": "completed" }, { "field": "list", "value": "L-1" }] }),
+    )
+    .await?;
+    assert_eq!(
+        ids(structured.structured_content.unwrap_or_default()),
+        vec![json!("BBBB-2222")]
+    );
+
+    let malformed = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "query": "leche priority>>high" }),
+    )
+    .await?;
+    assert_eq!(malformed.is_error, Some(true));
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&malformed).unwrap_or_default())?;
+    assert_eq!(error["field"], json!("query"));
+    let message = error["message"].as_str().unwrap_or_default();
+    assert!(
+        message.contains("`priority>>high` at column 7"),
+        "{message}"
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_pages_with_stable_cursors_and_rejects_foreign_ones() -> Result<()> {
+    let server = TestServer::start().await?;
+    server.fake.respond("list", json!([]))?;
+    let mut reminders = Vec::new();
+    for (id, title, due) in [
+        ("CCCC-3333", "Gamma", json!("2026-10-22")),
+        ("AAAA-1111", "Alpha", json!("2026-10-20")),
+        ("DDDD-4444", "Delta", json!(null)),
+        ("BBBB-2222", "Beta", json!("2026-10-21")),
+    ] {
+        let mut item = reminder(id, title, "Inbox");
+        item["dueDate"] = due;
+        reminders.push(item);
+    }
+    server.fake.respond("show", json!(reminders))?;
+    let client = server.client().await?;
+
+    let ids = |payload: &serde_json::Value| {
+        payload["reminders"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|reminder| reminder["id"].clone())
+            .collect::<Vec<_>>()
+    };
+    let first = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "sortBy": "due", "limit": 3 }),
+    )
+    .await?;
+    assert_ne!(first.is_error, Some(true), "{:?}", text_content(&first));
+    let first = first.structured_content.unwrap_or_default();
+    assert_eq!(
+        ids(&first),
+        vec![json!("AAAA-1111"), json!("BBBB-2222"), json!("CCCC-3333")]
+    );
+    assert_eq!(first["total"], json!(4));
+
+    let second = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "sortBy": "due", "limit": 3, "cursor": first["nextCursor"] }),
+    )
+    .await?;
+    let second = second.structured_content.unwrap_or_default();
+    assert_eq!(ids(&second), vec![json!("DDDD-4444")]);
+    assert_eq!(second.get("nextCursor"), None);
+
+    let foreign = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "filter": "today", "sortBy": "due", "limit": 3, "cursor": first["nextCursor"] }),
+    )
+    .await?;
+    assert_eq!(foreign.is_error, Some(true));
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&foreign).unwrap_or_default())?;
+    assert_eq!(error["field"], json!("cursor"));
+
+    let resource = client
+        .read_resource(ReadResourceRequestParams {
+            meta: None,
+            uri: "remindctl://reminders/all?sortBy=title&order=desc&limit=1".to_owned(),
+        })
+        .await?;
+    let Some(ResourceContents::TextResourceContents { text, .. }) = resource.contents.first()
+    else {
+        anyhow::bail!("expected a text resource");
+    };
+    let page: serde_json::Value = serde_json::from_str(text)?;
+    assert_eq!(ids(&page), vec![json!("CCCC-3333")]);
+    assert_eq!(page["total"], json!(4));
+    assert!(page["nextCursor"].is_string());
+
+    let encoded = client
+        .read_resource(ReadResourceRequestParams {
+            meta: None,
+            uri: "remindctl://reminders/all?sortBy=title&order=desc&limit=1&fields=id%2Ctitle"
+                .to_owned(),
+        })
+        .await?;
+    let Some(ResourceContents::TextResourceContents { text, .. }) = encoded.contents.first() else {
+        anyhow::bail!("expected a text resource");
+    };
+    let page: serde_json::Value = serde_json::from_str(text)?;
+    assert_eq!(
+        page["reminders"][0],
+        json!({ "id": "CCCC-3333", "title": "Gamma" })
+    );
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_tools_project_fields_and_render_compact_or_table_output() -> Result<()> {
+    let server = TestServer::start().await?;
+    server.fake.respond("list", json!([]))?;
+    let mut rent = reminder("AAAA-1111", "Pay rent", "Home");
+    rent["notes"] = json!("x".repeat(200));
+    rent["priority"] = json!("high");
+    server.fake.respond("show", json!([rent]))?;
+    let client = server.client().await?;
+
+    let tools = client.list_all_tools().await?;
+    let schema = tools
+        .iter()
+        .find(|tool| tool.name == "reminders_list")
+        .and_then(|tool| tool.output_schema.clone())
+        .unwrap_or_default();
+    assert_eq!(schema["$defs"]["Reminder"].get("required"), None);
+
+    let projected = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "filter": "all", "fields": ["title"] }),
+    )
+    .await?;
+    let projected = projected.structured_content.unwrap_or_default();
+    assert_eq!(
+        projected["reminders"][0],
+        json!({ "id": "AAAA-1111", "title": "Pay rent" })
+    );
+
+    let compact = call_tool(
+        &client,
+        "reminders_search",
+        json!({ "query": "rent", "format": "compact" }),
+    )
+    .await?;
+    let compact = compact.structured_content.unwrap_or_default();
+    let row = &compact["results"][0]["reminder"];
+    assert_eq!(
+        (&row["i"], &row["p"]),
+        (&json!("AAAA-1111"), &json!("high"))
+    );
+    assert_eq!(
+        row["n"].as_str().map(|notes| notes.chars().count()),
+        Some(81)
+    );
+    assert_eq!(row.get("c"), None);
+
+    let table = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "filter": "all", "fields": ["title", "priority"], "format": "table" }),
+    )
+    .await?;
+    assert_eq!(
+        text_content(&table).as_deref(),
+        Some("id\ttitle\tpriority\nAAAA-1111\tPay rent\thigh")
+    );
+    assert_eq!(
+        table.structured_content.unwrap_or_default()["reminders"][0]["priority"],
+        json!("high")
+    );
+
+    let unknown = call_tool(&client, "reminders_list", json!({ "fields": ["colour"] })).await?;
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&unknown).unwrap_or_default())?;
+    assert_eq!(error["field"], json!("fields"));
+
+    let resource = client
+        .read_resource(ReadResourceRequestParams {
+            meta: None,
+            uri: "remindctl://lists/by-name/Home/reminders?fields=title&format=table".to_owned(),
+        })
+        .await?;
+    let Some(ResourceContents::TextResourceContents { text, .. }) = resource.contents.first()
+    else {
+        anyhow::bail!("expected a text resource");
+    };
+    assert_eq!(text, "id\ttitle\nAAAA-1111\tPay rent");
+    client.cancel().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_scopes_to_several_lists_and_excludes_others() -> Result<()> {
+    let server = TestServer::start().await?;
+    server.fake.respond(
+        "list",
+        json!([
+            { "id": "L-1", "title": "Work" },
+            { "id": "L-2", "title": "Errands" },
+            { "id": "L-3", "title": "Compras" }
+        ]),
+    )?;
+    let mut reminders = Vec::new();
+    for (id, list_id, list) in [
+        ("AAAA-1111", "L-1", "Work"),
+        ("BBBB-2222", "L-3", "Compras"),
+        ("CCCC-3333", "L-2", "Errands"),
+    ] {
+        let mut item = reminder(id, "Task", list);
+        item["listID"] = json!(list_id);
+        reminders.push(item);
+    }
+    server.fake.respond("show", json!(reminders))?;
+    let client = server.client().await?;
+    let ids = |result: rmcp::model::CallToolResult| {
+        result.structured_content.unwrap_or_default()["reminders"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|reminder| reminder["id"].clone())
+            .collect::<Vec<_>>()
+    };
+
+    let both = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "listNames": ["Errands"], "listIds": ["L-1"] }),
+    )
+    .await?;
+    assert_eq!(ids(both), vec![json!("AAAA-1111"), json!("CCCC-3333")]);
+    assert!(
+        server
+            .fake
+            .calls()?
+            .iter()
+            .any(|call| call.first().map(String::as_str) == Some("show")
+                && !call.iter().any(|arg| arg == "--list")),
+        "several lists read one snapshot"
+    );
+
+    let excluded = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "excludeListNames": ["compras"] }),
+    )
+    .await?;
+    assert_eq!(ids(excluded), vec![json!("AAAA-1111"), json!("CCCC-3333")]);
+
+    let unknown = call_tool(
+        &client,
+        "reminders_list",
+        json!({ "listNames": ["Work", "Gym"], "excludeListIds": ["L-9"] }),
+    )
+    .await?;
+    assert_eq!(unknown.is_error, Some(true));
+    let error: serde_json::Value =
+        serde_json::from_str(&text_content(&unknown).unwrap_or_default())?;
+    assert_eq!(error["code"], json!("list_not_found"));
+    let message = error["message"].as_str().unwrap_or_default();
+    assert!(
+        message.contains("listNames 'Gym'") && message.contains("excludeListIds 'L-9'"),
+        "{message}"
+    );
+    client.cancel().await?;
+    Ok(())
+}